@@ -36,20 +36,8 @@ impl AuthBackend {
 
         // Otherwise, we'll use the plaintext credential store
         let path = TextCredentialStore::default_file()?;
-        match TextCredentialStore::read(&path).await {
-            Ok((store, lock)) => Ok(Self::TextStore(store, lock)),
-            Err(err)
-                if err
-                    .as_io_error()
-                    .is_some_and(|err| err.kind() == std::io::ErrorKind::NotFound) =>
-            {
-                Ok(Self::TextStore(
-                    TextCredentialStore::default(),
-                    TextCredentialStore::lock(&path).await?,
-                ))
-            }
-            Err(err) => Err(err),
-        }
+        let (store, lock) = TextCredentialStore::read_or_default(&path).await?;
+        Ok(Self::TextStore(store, lock))
     }
 }
 
@@ -302,8 +290,29 @@ impl TextCredentialStore {
         Ok((store, lock))
     }
 
+    /// Read credentials from a file, or start with an empty store if the file doesn't exist.
+    ///
+    /// Returns [`TextCredentialStore`] and a [`LockedFile`] to hold if mutating the store.
+    pub async fn read_or_default<P: AsRef<Path>>(
+        path: P,
+    ) -> Result<(Self, LockedFile), TomlCredentialError> {
+        match Self::read(path.as_ref()).await {
+            Ok((store, lock)) => Ok((store, lock)),
+            Err(err)
+                if err
+                    .as_io_error()
+                    .is_some_and(|err| err.kind() == std::io::ErrorKind::NotFound) =>
+            {
+                Ok((Self::default(), Self::lock(path.as_ref()).await?))
+            }
+            Err(err) => Err(err),
+        }
+    }
+
     /// Persist credentials to a file.
     ///
+    /// On Unix, the file is only readable and writable by the current user.
+    ///
     /// Requires a [`LockedFile`] from [`TextCredentialStore::lock`] or
     /// [`TextCredentialStore::read`] to ensure exclusive access.
     pub fn write<P: AsRef<Path>>(
@@ -328,8 +337,9 @@ impl TextCredentialStore {
                 .ok_or(TomlCredentialError::CredentialsDirError)?,
         )?;
 
-        // TODO(zanieb): We should use an atomic write here
-        fs::write(path, content)?;
+        // The file is created with restricted permissions before any credentials are written.
+        uv_fs::write_atomic_private_sync(path.as_ref(), content)?;
+
         Ok(())
     }
 
@@ -525,6 +535,33 @@ password = "pass2"
         assert!(content.contains("testuser"));
     }
 
+    #[tokio::test]
+    #[cfg(unix)]
+    async fn test_write_restricts_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("credentials.toml");
+
+        let (mut store, lock) = TextCredentialStore::read_or_default(&path).await.unwrap();
+        store.insert(
+            Service::from_str("https://example.com").unwrap(),
+            Credentials::basic(Some("user".to_string()), Some("pass".to_string())),
+        );
+        store.write(&path, lock).unwrap();
+
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+
+        // An existing, world-readable file is replaced rather than written in place.
+        fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+        let (store, lock) = TextCredentialStore::read(&path).await.unwrap();
+        store.write(&path, lock).unwrap();
+
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
     #[test]
     fn test_prefix_matching() {
         let mut store = TextCredentialStore::default();
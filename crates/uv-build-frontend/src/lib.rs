@@ -58,6 +58,19 @@ static DEFAULT_BACKEND: LazyLock<Pep517Backend> = LazyLock::new(|| Pep517Backend
     )],
 });
 
+/// Python environment variables that can redirect the interpreter to another standard library,
+/// other modules, or other startup code, removed from build backend processes with
+/// [`SourceBuildContext::with_clean_env`].
+const INTERFERING_ENV_VARS: &[&str] = &[
+    EnvVars::PYTHONPATH,
+    EnvVars::PYTHONHOME,
+    EnvVars::PYTHONSTARTUP,
+    EnvVars::PYTHONUSERBASE,
+    EnvVars::PYTHONPLATLIBDIR,
+    EnvVars::PYTHONEXECUTABLE,
+    EnvVars::PYVENV_LAUNCHER,
+];
+
 /// A `pyproject.toml` as specified in PEP 517.
 #[derive(Deserialize, Debug)]
 #[serde(rename_all = "kebab-case")]
@@ -223,6 +236,8 @@ pub struct SourceBuildContext {
     default_resolution: Arc<Mutex<Option<ResolvedRequirements>>>,
     /// A shared semaphore to limit the number of concurrent builds.
     concurrent_build_slots: Arc<Semaphore>,
+    /// Whether to remove [`INTERFERING_ENV_VARS`] from the build backend's environment.
+    clean_env: bool,
 }
 
 impl SourceBuildContext {
@@ -231,8 +246,17 @@ impl SourceBuildContext {
         Self {
             default_resolution: Arc::default(),
             concurrent_build_slots,
+            clean_env: false,
         }
     }
+
+    /// Remove Python environment variables that change how the interpreter locates modules or
+    /// its standard library (e.g., `PYTHONPATH` and `PYTHONHOME`) before invoking build backends.
+    #[must_use]
+    pub fn with_clean_env(mut self, clean_env: bool) -> Self {
+        self.clean_env = clean_env;
+        self
+    }
 }
 
 /// Holds the state through a series of PEP 517 frontend to backend calls or a single `setup.py`
@@ -338,11 +362,10 @@ impl SourceBuild {
             .or(fallback_package_version)
             .cloned();
 
-        let extra_build_dependencies = package_name
-            .as_ref()
-            .and_then(|name| extra_build_requires.get(name).cloned())
-            .unwrap_or_default()
-            .into_iter()
+        let extra_build_dependencies = extra_build_requires
+            .for_package(package_name.as_ref())
+            .iter()
+            .cloned()
             .map(|requirement| {
                 match requirement {
                     ExtraBuildRequirement {
@@ -351,7 +374,8 @@ impl SourceBuild {
                     } if requirement.source.is_empty() => {
                         Err(Error::UnmatchedRuntime(
                             requirement.name.clone(),
-                            // SAFETY: if `package_name` is `None`, the iterator is empty.
+                            // SAFETY: only per-package requirements can match the runtime,
+                            // and they're only returned if `package_name` is `Some`.
                             package_name.clone().unwrap(),
                         ))
                     }
@@ -440,7 +464,11 @@ impl SourceBuild {
 
         // Create the PEP 517 build environment. If build isolation is disabled, we assume the build
         // environment is already setup.
-        let runner = PythonRunner::new(source_build_context.concurrent_build_slots.clone(), level);
+        let runner = PythonRunner::new(
+            source_build_context.concurrent_build_slots.clone(),
+            source_build_context.clean_env,
+            level,
+        );
         if build_isolation.is_isolated(package_name.as_ref()) {
             debug!("Creating PEP 517 build environment");
 
@@ -1146,6 +1174,8 @@ async fn create_pep517_build_environment(
 #[derive(Debug)]
 struct PythonRunner {
     concurrent_build_slots: Arc<Semaphore>,
+    /// Whether to remove [`INTERFERING_ENV_VARS`] from the script's environment.
+    clean_env: bool,
     level: BuildOutput,
 }
 
@@ -1158,9 +1188,10 @@ struct PythonRunnerOutput {
 
 impl PythonRunner {
     /// Create a `PythonRunner` with the provided shared concurrency semaphore and output level.
-    fn new(concurrent_build_slots: Arc<Semaphore>, level: BuildOutput) -> Self {
+    fn new(concurrent_build_slots: Arc<Semaphore>, clean_env: bool, level: BuildOutput) -> Self {
         Self {
             concurrent_build_slots,
+            clean_env,
             level,
         }
     }
@@ -1200,7 +1231,15 @@ impl PythonRunner {
 
         let _permit = self.concurrent_build_slots.acquire().await.unwrap();
 
-        let mut child = Command::new(venv.python_executable())
+        let mut command = Command::new(venv.python_executable());
+        // Remove interfering variables first, such that explicitly configured build variables
+        // still apply.
+        if self.clean_env {
+            for name in INTERFERING_ENV_VARS {
+                command.env_remove(name);
+            }
+        }
+        let mut child = command
             .args(["-c", script])
             .current_dir(source_tree.simplified())
             .envs(environment_variables)
@@ -166,11 +166,20 @@ pub struct Cache {
     root: PathBuf,
     /// The refresh strategy to use when reading from the cache.
     refresh: Refresh,
+    /// A cutoff after which all cached package metadata (e.g., index pages and wheel metadata)
+    /// must be revalidated, in addition to the refresh strategy. Cached distributions are
+    /// unaffected.
+    metadata_refresh: Option<Timestamp>,
     /// A temporary cache directory, if the user requested `--no-cache`.
     ///
     /// Included to ensure that the temporary directory exists for the length of the operation, but
     /// is dropped at the end as appropriate.
     temp_dir: Option<Arc<tempfile::TempDir>>,
+    /// A temporary directory for downloaded wheels, if the user requested `--no-wheel-cache`.
+    ///
+    /// When set, the [`CacheBucket::Wheels`] and [`CacheBucket::Archive`] buckets are redirected
+    /// into this directory, while all other buckets remain in the cache.
+    wheel_temp_dir: Option<Arc<tempfile::TempDir>>,
     /// Ensure that `uv cache` operations don't remove items from the cache that are used by another
     /// uv process.
     lock_file: Option<Arc<LockedFile>>,
@@ -182,7 +191,9 @@ impl Cache {
         Self {
             root: root.into(),
             refresh: Refresh::None(Timestamp::now()),
+            metadata_refresh: None,
             temp_dir: None,
+            wheel_temp_dir: None,
             lock_file: None,
         }
     }
@@ -193,7 +204,9 @@ impl Cache {
         Ok(Self {
             root: temp_dir.path().to_path_buf(),
             refresh: Refresh::None(Timestamp::now()),
+            metadata_refresh: None,
             temp_dir: Some(Arc::new(temp_dir)),
+            wheel_temp_dir: None,
             lock_file: None,
         })
     }
@@ -204,12 +217,46 @@ impl Cache {
         Self { refresh, ..self }
     }
 
+    /// Refresh all cached package metadata that was written before the given [`Timestamp`].
+    ///
+    /// Applies in addition to the [`Refresh`] policy, but only to entries validated via
+    /// [`Cache::metadata_freshness`].
+    #[must_use]
+    pub fn with_metadata_refresh(self, timestamp: Timestamp) -> Self {
+        Self {
+            metadata_refresh: Some(timestamp),
+            ..self
+        }
+    }
+
+    /// Avoid reading or writing downloaded wheels from the cache, instead using a temporary
+    /// directory for the duration of the operation.
+    ///
+    /// Package metadata, like index pages and wheel metadata, continues to be read from and written
+    /// to the cache, as long as it's accessed via [`Cache::metadata_entry`].
+    ///
+    /// The temporary directory is created within the cache, such that wheels can still be linked
+    /// into the environment, so the cache must be initialized first.
+    pub fn with_temporary_wheels(self) -> io::Result<Self> {
+        if self.is_temporary() {
+            return Ok(self);
+        }
+        fs_err::create_dir_all(self.bucket(CacheBucket::Builds))?;
+        let wheel_temp_dir = tempfile::tempdir_in(self.bucket(CacheBucket::Builds))?;
+        Ok(Self {
+            wheel_temp_dir: Some(Arc::new(wheel_temp_dir)),
+            ..self
+        })
+    }
+
     /// Acquire a lock that allows removing entries from the cache.
     pub async fn with_exclusive_lock(self) -> Result<Self, LockedFileError> {
         let Self {
             root,
             refresh,
+            metadata_refresh,
             temp_dir,
+            wheel_temp_dir,
             lock_file,
         } = self;
 
@@ -231,7 +278,9 @@ impl Cache {
         Ok(Self {
             root,
             refresh,
+            metadata_refresh,
             temp_dir,
+            wheel_temp_dir,
             lock_file: Some(Arc::new(lock_file)),
         })
     }
@@ -239,11 +288,14 @@ impl Cache {
     /// Acquire a lock that allows removing entries from the cache, if available.
     ///
     /// If the lock is not immediately available, returns [`Err`] with self.
+    #[expect(clippy::result_large_err)]
     pub fn with_exclusive_lock_no_wait(self) -> Result<Self, Self> {
         let Self {
             root,
             refresh,
+            metadata_refresh,
             temp_dir,
+            wheel_temp_dir,
             lock_file,
         } = self;
 
@@ -255,13 +307,17 @@ impl Cache {
             Some(lock_file) => Ok(Self {
                 root,
                 refresh,
+                metadata_refresh,
                 temp_dir,
+                wheel_temp_dir,
                 lock_file: Some(Arc::new(lock_file)),
             }),
             None => Err(Self {
                 root,
                 refresh,
+                metadata_refresh,
                 temp_dir,
+                wheel_temp_dir,
                 lock_file,
             }),
         }
@@ -279,7 +335,12 @@ impl Cache {
 
     /// The folder for a specific cache bucket
     pub fn bucket(&self, cache_bucket: CacheBucket) -> PathBuf {
-        self.root.join(cache_bucket.to_str())
+        match (&self.wheel_temp_dir, cache_bucket) {
+            (Some(wheel_temp_dir), CacheBucket::Wheels | CacheBucket::Archive) => {
+                wheel_temp_dir.path().join(cache_bucket.to_str())
+            }
+            _ => self.root.join(cache_bucket.to_str()),
+        }
     }
 
     /// Compute an entry in the cache.
@@ -297,6 +358,19 @@ impl Cache {
         CacheEntry::new(self.bucket(cache_bucket).join(dir), file)
     }
 
+    /// Compute an entry in the cache for package metadata.
+    ///
+    /// Unlike [`Cache::entry`], the entry is always in the cache itself, even if downloaded wheels
+    /// are written to a temporary directory (see [`Cache::with_temporary_wheels`]).
+    pub fn metadata_entry(
+        &self,
+        cache_bucket: CacheBucket,
+        dir: impl AsRef<Path>,
+        file: impl AsRef<Path>,
+    ) -> CacheEntry {
+        CacheEntry::new(self.root.join(cache_bucket.to_str()).join(dir), file)
+    }
+
     /// Return the path to an archive in the cache.
     pub fn archive(&self, id: &ArchiveId) -> PathBuf {
         self.bucket(CacheBucket::Archive).join(id)
@@ -343,9 +417,41 @@ impl Cache {
         entry: &CacheEntry,
         package: Option<&PackageName>,
         path: Option<&Path>,
+    ) -> io::Result<Freshness> {
+        Self::freshness_for(&self.refresh, entry, package, path)
+    }
+
+    /// Returns the [`Freshness`] for a cache entry that holds package metadata, validating it
+    /// against both the [`Refresh`] policy and the metadata refresh cutoff, if any.
+    ///
+    /// Cached distributions should be validated via [`Cache::freshness`] instead, such that they
+    /// can be reused when only the metadata is refreshed.
+    pub fn metadata_freshness(
+        &self,
+        entry: &CacheEntry,
+        package: Option<&PackageName>,
+        path: Option<&Path>,
+    ) -> io::Result<Freshness> {
+        match Self::freshness_for(&self.refresh, entry, package, path)? {
+            Freshness::Fresh => match self.metadata_refresh {
+                Some(timestamp) => {
+                    Self::freshness_for(&Refresh::All(timestamp), entry, package, path)
+                }
+                None => Ok(Freshness::Fresh),
+            },
+            freshness => Ok(freshness),
+        }
+    }
+
+    /// Returns the [`Freshness`] for a cache entry under the given [`Refresh`] policy.
+    fn freshness_for(
+        refresh: &Refresh,
+        entry: &CacheEntry,
+        package: Option<&PackageName>,
+        path: Option<&Path>,
     ) -> io::Result<Freshness> {
         // Grab the cutoff timestamp, if it's relevant.
-        let timestamp = match &self.refresh {
+        let timestamp = match refresh {
             Refresh::None(_) => return Ok(Freshness::Fresh),
             Refresh::All(timestamp) => timestamp,
             Refresh::Packages(packages, paths, timestamp) => {
@@ -403,6 +509,12 @@ impl Cache {
         self.temp_dir.is_some()
     }
 
+    /// Returns `true` if the [`Cache`] stores wheels in a temporary directory, which is removed
+    /// once the [`Cache`] is dropped (see [`Cache::with_temporary_wheels`]).
+    pub fn has_temporary_wheels(&self) -> bool {
+        self.wheel_temp_dir.is_some()
+    }
+
     /// Populate the cache scaffold.
     fn create_base_files(root: &PathBuf) -> io::Result<()> {
         // Create the cache directory, if it doesn't exist.
@@ -170,7 +170,7 @@ impl<'a> FlatIndexClient<'a> {
         let cache_control = match self.connectivity {
             Connectivity::Online => CacheControl::from(
                 self.cache
-                    .freshness(&cache_entry, None, None)
+                    .metadata_freshness(&cache_entry, None, None)
                     .map_err(ErrorKind::Io)?,
             ),
             Connectivity::Offline => CacheControl::AllowStale,
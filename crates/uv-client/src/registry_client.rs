@@ -524,7 +524,7 @@ impl RegistryClient {
                 } else {
                     CacheControl::from(
                         self.cache
-                            .freshness(&cache_entry, Some(package_name), None)
+                            .metadata_freshness(&cache_entry, Some(package_name), None)
                             .map_err(ErrorKind::Io)?,
                     )
                 }
@@ -784,7 +784,7 @@ impl RegistryClient {
                 } else {
                     CacheControl::from(
                         self.cache
-                            .freshness(&cache_entry, None, None)
+                            .metadata_freshness(&cache_entry, None, None)
                             .map_err(ErrorKind::Io)?,
                     )
                 }
@@ -1011,7 +1011,7 @@ impl RegistryClient {
             let path = format!("{}.metadata", url.path());
             url.set_path(&path);
 
-            let cache_entry = self.cache.entry(
+            let cache_entry = self.cache.metadata_entry(
                 CacheBucket::Wheels,
                 WheelCache::Index(index).wheel_dir(filename.name.as_ref()),
                 format!("{}.msgpack", filename.cache_key()),
@@ -1023,7 +1023,7 @@ impl RegistryClient {
                     } else {
                         CacheControl::from(
                             self.cache
-                                .freshness(&cache_entry, Some(&filename.name), None)
+                                .metadata_freshness(&cache_entry, Some(&filename.name), None)
                                 .map_err(ErrorKind::Io)?,
                         )
                     }
@@ -1087,7 +1087,7 @@ impl RegistryClient {
         cache_shard: WheelCache<'data>,
         capabilities: &'data IndexCapabilities,
     ) -> Result<ResolutionMetadata, Error> {
-        let cache_entry = self.cache.entry(
+        let cache_entry = self.cache.metadata_entry(
             CacheBucket::Wheels,
             cache_shard.wheel_dir(filename.name.as_ref()),
             format!("{}.msgpack", filename.cache_key()),
@@ -1100,14 +1100,14 @@ impl RegistryClient {
                     } else {
                         CacheControl::from(
                             self.cache
-                                .freshness(&cache_entry, Some(&filename.name), None)
+                                .metadata_freshness(&cache_entry, Some(&filename.name), None)
                                 .map_err(ErrorKind::Io)?,
                         )
                     }
                 } else {
                     CacheControl::from(
                         self.cache
-                            .freshness(&cache_entry, Some(&filename.name), None)
+                            .metadata_freshness(&cache_entry, Some(&filename.name), None)
                             .map_err(ErrorKind::Io)?,
                     )
                 }
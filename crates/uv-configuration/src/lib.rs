@@ -17,6 +17,7 @@ pub use package_options::*;
 pub use project_build_backend::*;
 pub use proxy_url::*;
 pub use required_version::*;
+pub use show_plan::*;
 pub use sources::*;
 pub use target_triple::*;
 pub use threading::*;
@@ -44,6 +45,7 @@ mod package_options;
 mod project_build_backend;
 mod proxy_url;
 mod required_version;
+mod show_plan;
 mod sources;
 mod target_triple;
 mod threading;
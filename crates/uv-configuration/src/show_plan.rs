@@ -0,0 +1,26 @@
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ShowPlan {
+    /// Print the installation plan and prompt for confirmation before executing it.
+    Confirm,
+    /// Print the installation plan and execute it without prompting.
+    Enabled,
+    /// Execute the installation plan without printing it.
+    #[default]
+    Disabled,
+}
+
+impl ShowPlan {
+    /// Determine the [`ShowPlan`] setting based on the command-line arguments.
+    pub fn from_args(show_plan: bool, yes: bool) -> Self {
+        match (show_plan, yes) {
+            (false, _) => Self::Disabled,
+            (true, false) => Self::Confirm,
+            (true, true) => Self::Enabled,
+        }
+    }
+
+    /// Returns `true` if the installation plan should be printed.
+    pub const fn enabled(&self) -> bool {
+        matches!(self, Self::Confirm | Self::Enabled)
+    }
+}
@@ -180,6 +180,14 @@ impl<'a> BuildDispatch<'a> {
             .collect();
         self
     }
+
+    /// Remove Python environment variables that can interfere with build backends, such as
+    /// `PYTHONPATH` and `PYTHONHOME`, before invoking them.
+    #[must_use]
+    pub fn with_clean_env(mut self, clean_env: bool) -> Self {
+        self.source_build_context = self.source_build_context.with_clean_env(clean_env);
+        self
+    }
 }
 
 #[allow(refining_impl_trait)]
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
@@ -25,19 +26,24 @@ pub enum ExtraBuildRequiresError {
 
 /// Lowered extra build dependencies with source resolution applied.
 #[derive(Debug, Clone, Default)]
-pub struct ExtraBuildRequires(BTreeMap<PackageName, Vec<ExtraBuildRequirement>>);
+pub struct ExtraBuildRequires {
+    /// The extra build requirements for each package.
+    packages: BTreeMap<PackageName, Vec<ExtraBuildRequirement>>,
+    /// The extra build requirements for every package (e.g., `--build-isolation-packages`).
+    global: Vec<ExtraBuildRequirement>,
+}
 
 impl std::ops::Deref for ExtraBuildRequires {
     type Target = BTreeMap<PackageName, Vec<ExtraBuildRequirement>>;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.packages
     }
 }
 
 impl std::ops::DerefMut for ExtraBuildRequires {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.0
+        &mut self.packages
     }
 }
 
@@ -46,7 +52,7 @@ impl IntoIterator for ExtraBuildRequires {
     type IntoIter = std::collections::btree_map::IntoIter<PackageName, Vec<ExtraBuildRequirement>>;
 
     fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter()
+        self.packages.into_iter()
     }
 }
 
@@ -54,7 +60,10 @@ impl FromIterator<(PackageName, Vec<ExtraBuildRequirement>)> for ExtraBuildRequi
     fn from_iter<T: IntoIterator<Item = (PackageName, Vec<ExtraBuildRequirement>)>>(
         iter: T,
     ) -> Self {
-        Self(iter.into_iter().collect())
+        Self {
+            packages: iter.into_iter().collect(),
+            global: Vec::new(),
+        }
     }
 }
 
@@ -80,9 +89,44 @@ impl CacheKey for ExtraBuildRequirement {
 }
 
 impl ExtraBuildRequires {
+    /// Add the given requirements to the build environment of every package.
+    #[must_use]
+    pub fn with_global(mut self, requirements: impl IntoIterator<Item = Requirement>) -> Self {
+        self.global.extend(
+            requirements
+                .into_iter()
+                .map(|requirement| ExtraBuildRequirement {
+                    requirement,
+                    match_runtime: false,
+                }),
+        );
+        self
+    }
+
+    /// Return the extra build requirements for the given package, including those that apply to
+    /// every package.
+    pub fn for_package(&self, name: Option<&PackageName>) -> Cow<'_, [ExtraBuildRequirement]> {
+        let packages = name
+            .and_then(|name| self.packages.get(name))
+            .map(Vec::as_slice)
+            .unwrap_or(&[]);
+        if self.global.is_empty() {
+            Cow::Borrowed(packages)
+        } else if packages.is_empty() {
+            Cow::Borrowed(&self.global)
+        } else {
+            Cow::Owned([packages, &self.global].concat())
+        }
+    }
+
     /// Apply runtime constraints from a resolution to the extra build requirements.
-    pub fn match_runtime(self, resolution: &Resolution) -> Result<Self, ExtraBuildRequiresError> {
-        self.into_iter()
+    pub fn match_runtime(
+        mut self,
+        resolution: &Resolution,
+    ) -> Result<Self, ExtraBuildRequiresError> {
+        let global = std::mem::take(&mut self.global);
+        let packages = self
+            .into_iter()
             .filter(|(_, requirements)| !requirements.is_empty())
             .filter(|(name, _)| resolution.distributions().any(|dist| dist.name() == name))
             .map(|(name, requirements)| {
@@ -133,7 +177,8 @@ impl ExtraBuildRequires {
                     .collect::<Result<Vec<_>, _>>()?;
                 Ok::<_, ExtraBuildRequiresError>((name, requirements))
             })
-            .collect::<Result<Self, _>>()
+            .collect::<Result<Self, _>>()?;
+        Ok(Self { global, ..packages })
     }
 }
 
@@ -7,9 +7,9 @@ use uv_pep440::Version;
 use uv_pypi_types::Yanked;
 
 use crate::{
-    BuiltDist, Dist, DistributionId, DistributionMetadata, Identifier, IndexUrl, InstalledDist,
-    Name, PrioritizedDist, RegistryBuiltWheel, RegistrySourceDist, ResourceId, SourceDist,
-    VersionId, VersionOrUrlRef,
+    BuiltDist, Dist, DistributionId, DistributionMetadata, File, Identifier, IndexUrl,
+    InstalledDist, Name, PrioritizedDist, RegistryBuiltWheel, RegistrySourceDist, ResourceId,
+    SourceDist, VersionId, VersionOrUrlRef,
 };
 
 /// A distribution that can be used for resolution and installation.
@@ -71,6 +71,14 @@ impl ResolvedDist {
         }
     }
 
+    /// Returns the [`File`] of the distribution, if it's from a registry.
+    pub fn file(&self) -> Option<&File> {
+        match self {
+            Self::Installable { dist, .. } => dist.file(),
+            Self::Installed { .. } => None,
+        }
+    }
+
     /// Returns the [`Yanked`] status of the distribution, if available.
     pub fn yanked(&self) -> Option<&Yanked> {
         match self {
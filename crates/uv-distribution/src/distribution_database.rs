@@ -1,3 +1,4 @@
+use std::fmt::Display;
 use std::future::Future;
 use std::io;
 use std::path::Path;
@@ -29,7 +30,7 @@ use uv_platform_tags::Tags;
 use uv_pypi_types::{HashDigest, HashDigests, PyProjectToml};
 use uv_redacted::DisplaySafeUrl;
 use uv_types::{BuildContext, BuildStack};
-use uv_warnings::warn_user_once;
+use uv_warnings::{warn_user, warn_user_once};
 
 use crate::archive::Archive;
 use uv_python::PythonVariant;
@@ -666,6 +667,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
         let download = |response: reqwest::Response| {
             async {
+                self.client.warn_trusted_host(query_url, dist);
+
                 let size = size.or_else(|| content_length(&response));
 
                 let progress = self
@@ -843,6 +846,8 @@ impl<'a, Context: BuildContext> DistributionDatabase<'a, Context> {
 
         let download = |response: reqwest::Response| {
             async {
+                self.client.warn_trusted_host(query_url, dist);
+
                 let size = size.or_else(|| content_length(&response));
 
                 let progress = self
@@ -1263,6 +1268,22 @@ impl<'a> ManagedClient<'a> {
     {
         f(self.unmanaged, &self.control).await
     }
+
+    /// Warn that a distribution is being downloaded from a host that was allowed via
+    /// `--allow-insecure-host`, i.e., over plain HTTP or without certificate verification.
+    pub(crate) fn warn_trusted_host(&self, url: &DisplaySafeUrl, dist: impl Display) {
+        if self.unmanaged.disable_ssl(url) {
+            let transport = if url.scheme() == "https" {
+                "without certificate verification"
+            } else {
+                "over an unencrypted connection"
+            };
+            warn_user!(
+                "Downloading `{dist}` from `{}` {transport} (allowed by `--allow-insecure-host`)",
+                url.host_str().unwrap_or_default()
+            );
+        }
+    }
 }
 
 /// Returns the value of the `Content-Length` header from the [`reqwest::Response`], if present.
@@ -3,7 +3,7 @@ use std::path::Path;
 use uv_cache_info::CacheInfo;
 use uv_distribution_filename::WheelFilename;
 use uv_distribution_types::{BuildInfo, CachedDist, Dist, Hashed};
-use uv_metadata::read_flat_wheel_metadata;
+use uv_metadata::{find_flat_dist_info, read_dist_info_metadata, read_flat_wheel_metadata};
 use uv_pypi_types::{HashDigest, HashDigests, ResolutionMetadata};
 
 use crate::Error;
@@ -47,6 +47,21 @@ impl LocalWheel {
         read_flat_wheel_metadata(&self.filename, &self.archive)
             .map_err(|err| Error::WheelMetadata(self.archive.to_path_buf(), Box::new(err)))
     }
+
+    /// Read the SPDX license expression from a wheel's `METADATA` file, if it can be determined.
+    pub fn license(&self) -> Result<Option<String>, Error> {
+        find_flat_dist_info(&self.filename, &self.archive)
+            .and_then(|dist_info_prefix| {
+                let metadata = read_dist_info_metadata(&dist_info_prefix, &self.archive)?;
+                uv_pypi_types::parse_license(&metadata).map_err(|err| {
+                    uv_metadata::Error::InvalidMetadata(
+                        format!("{dist_info_prefix}.dist-info/METADATA"),
+                        Box::new(err),
+                    )
+                })
+            })
+            .map_err(|err| Error::WheelMetadata(self.archive.to_path_buf(), Box::new(err)))
+    }
 }
 
 impl Hashed for LocalWheel {
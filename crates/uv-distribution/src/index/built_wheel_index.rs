@@ -80,7 +80,7 @@ impl<'a> BuiltWheelIndex<'a> {
         let extra_build_deps = self.extra_build_requires_for(&source_dist.name);
         let extra_build_vars = self.extra_build_variables_for(&source_dist.name);
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars);
+            BuildInfo::from_settings(&config_settings, &extra_build_deps, extra_build_vars);
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -129,7 +129,7 @@ impl<'a> BuiltWheelIndex<'a> {
         let extra_build_deps = self.extra_build_requires_for(&source_dist.name);
         let extra_build_vars = self.extra_build_variables_for(&source_dist.name);
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars);
+            BuildInfo::from_settings(&config_settings, &extra_build_deps, extra_build_vars);
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -180,7 +180,7 @@ impl<'a> BuiltWheelIndex<'a> {
         let extra_build_deps = self.extra_build_requires_for(&source_dist.name);
         let extra_build_vars = self.extra_build_variables_for(&source_dist.name);
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars);
+            BuildInfo::from_settings(&config_settings, &extra_build_deps, extra_build_vars);
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -210,7 +210,7 @@ impl<'a> BuiltWheelIndex<'a> {
         let extra_build_deps = self.extra_build_requires_for(&source_dist.name);
         let extra_build_vars = self.extra_build_variables_for(&source_dist.name);
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_vars);
+            BuildInfo::from_settings(&config_settings, &extra_build_deps, extra_build_vars);
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -293,11 +293,8 @@ impl<'a> BuiltWheelIndex<'a> {
     }
 
     /// Determine the extra build requirements for the given package name.
-    fn extra_build_requires_for(&self, name: &PackageName) -> &[ExtraBuildRequirement] {
-        self.extra_build_requires
-            .get(name)
-            .map(Vec::as_slice)
-            .unwrap_or(&[])
+    fn extra_build_requires_for(&self, name: &PackageName) -> Cow<'_, [ExtraBuildRequirement]> {
+        self.extra_build_requires.for_package(Some(name))
     }
 
     /// Determine the extra build variables for the given package name.
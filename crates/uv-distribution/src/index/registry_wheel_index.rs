@@ -228,7 +228,7 @@ impl<'a> RegistryWheelIndex<'a> {
                     );
                     let build_info = BuildInfo::from_settings(
                         &config_settings,
-                        extra_build_deps,
+                        &extra_build_deps,
                         extra_build_vars,
                     );
                     let cache_shard = build_info
@@ -308,11 +308,8 @@ impl<'a> RegistryWheelIndex<'a> {
     fn extra_build_requires_for<'settings>(
         name: &PackageName,
         extra_build_requires: &'settings ExtraBuildRequires,
-    ) -> &'settings [ExtraBuildRequirement] {
-        extra_build_requires
-            .get(name)
-            .map(Vec::as_slice)
-            .unwrap_or(&[])
+    ) -> Cow<'settings, [ExtraBuildRequirement]> {
+        extra_build_requires.for_package(Some(name))
     }
 
     /// Determine the extra build variables for the given package name.
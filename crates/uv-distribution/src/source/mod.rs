@@ -429,14 +429,11 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
     }
 
     /// Determine the extra build dependencies for the given package name.
-    fn extra_build_dependencies_for(&self, name: Option<&PackageName>) -> &[ExtraBuildRequirement] {
-        name.and_then(|name| {
-            self.build_context
-                .extra_build_requires()
-                .get(name)
-                .map(Vec::as_slice)
-        })
-        .unwrap_or(&[])
+    fn extra_build_dependencies_for(
+        &self,
+        name: Option<&PackageName>,
+    ) -> Cow<'_, [ExtraBuildRequirement]> {
+        self.build_context.extra_build_requires().for_package(name)
     }
 
     /// Determine the extra build variables for the given package name.
@@ -487,7 +484,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+            BuildInfo::from_settings(&config_settings, &extra_build_deps, extra_build_variables);
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -708,7 +705,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+            BuildInfo::from_settings(&config_settings, &extra_build_deps, extra_build_variables);
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -795,6 +792,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             let query_url = url.clone();
 
             async {
+                client.warn_trusted_host(&query_url, source);
+
                 // At this point, we're seeing a new or updated source distribution. Initialize a
                 // new revision, to collect the source and built artifacts.
                 let revision = Revision::new();
@@ -890,7 +889,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+            BuildInfo::from_settings(&config_settings, &extra_build_deps, extra_build_variables);
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -1061,7 +1060,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+            BuildInfo::from_settings(&config_settings, &extra_build_deps, extra_build_variables);
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -1206,7 +1205,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+            BuildInfo::from_settings(&config_settings, &extra_build_deps, extra_build_variables);
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -1418,7 +1417,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+            BuildInfo::from_settings(&config_settings, &extra_build_deps, extra_build_variables);
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -1646,7 +1645,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+            BuildInfo::from_settings(&config_settings, &extra_build_deps, extra_build_variables);
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -1978,7 +1977,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
         let extra_build_deps = self.extra_build_dependencies_for(source.name());
         let extra_build_variables = self.extra_build_variables_for(source.name());
         let build_info =
-            BuildInfo::from_settings(&config_settings, extra_build_deps, extra_build_variables);
+            BuildInfo::from_settings(&config_settings, &extra_build_deps, extra_build_variables);
         let cache_shard = build_info
             .cache_shard()
             .map(|digest| cache_shard.shard(digest))
@@ -2267,6 +2266,8 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             let query_url = url.clone();
 
             async {
+                client.warn_trusted_host(&query_url, source);
+
                 // Take the union of the requested and existing hash algorithms.
                 let algorithms = {
                     let mut algorithms = hashes.algorithms();
@@ -253,6 +253,24 @@ pub fn write_atomic_sync(path: impl AsRef<Path>, data: impl AsRef<[u8]>) -> std:
     persist_with_retry_sync(temp_file, path.as_ref())
 }
 
+/// Write `data` to `path` atomically, such that only the current user can read the file.
+///
+/// Unlike [`write_atomic_sync`], the temporary file keeps the `0o600` permissions that
+/// [`NamedTempFile`] is created with on Unix, so the data is never readable by other users, not
+/// even before the rename.
+pub fn write_atomic_private_sync(
+    path: impl AsRef<Path>,
+    data: impl AsRef<[u8]>,
+) -> std::io::Result<()> {
+    let temp_file = NamedTempFile::new_in(
+        path.as_ref()
+            .parent()
+            .expect("Write path must have a parent"),
+    )?;
+    fs_err::write(&temp_file, &data)?;
+    persist_with_retry_sync(temp_file, path.as_ref())
+}
+
 /// Copy `from` to `to` atomically using a temporary file and atomic rename.
 pub fn copy_atomic_sync(from: impl AsRef<Path>, to: impl AsRef<Path>) -> std::io::Result<()> {
     let temp_file = tempfile_in(to.as_ref().parent().expect("Write path must have a parent"))?;
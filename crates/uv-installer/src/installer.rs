@@ -95,13 +95,7 @@ impl<'a> Installer<'a> {
             preview,
         } = self;
 
-        if cache.is_some_and(Cache::is_temporary) {
-            if link_mode.is_symlink() {
-                return Err(anyhow::anyhow!(
-                    "Symlink-based installation is not supported with `--no-cache`. The created environment will be rendered unusable by the removal of the cache."
-                ));
-            }
-        }
+        validate_link_mode(cache, link_mode)?;
 
         let (tx, rx) = oneshot::channel();
 
@@ -133,13 +127,7 @@ impl<'a> Installer<'a> {
     /// Install a set of wheels into a Python virtual environment synchronously.
     #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
     pub fn install_blocking(self, wheels: Vec<CachedDist>) -> Result<Vec<CachedDist>> {
-        if self.cache.is_some_and(Cache::is_temporary) {
-            if self.link_mode.is_symlink() {
-                return Err(anyhow::anyhow!(
-                    "Symlink-based installation is not supported with `--no-cache`. The created environment will be rendered unusable by the removal of the cache."
-                ));
-            }
-        }
+        validate_link_mode(self.cache, self.link_mode)?;
 
         install(
             wheels,
@@ -154,6 +142,25 @@ impl<'a> Installer<'a> {
     }
 }
 
+/// Reject symlink-based installation if the linked wheels are removed once uv exits, i.e., if the
+/// cache is temporary or stores wheels in a temporary directory.
+fn validate_link_mode(cache: Option<&Cache>, link_mode: LinkMode) -> Result<()> {
+    if !link_mode.is_symlink() {
+        return Ok(());
+    }
+    if cache.is_some_and(Cache::is_temporary) {
+        return Err(anyhow::anyhow!(
+            "Symlink-based installation is not supported with `--no-cache`. The created environment will be rendered unusable by the removal of the cache."
+        ));
+    }
+    if cache.is_some_and(Cache::has_temporary_wheels) {
+        return Err(anyhow::anyhow!(
+            "Symlink-based installation is not supported with `--no-wheel-cache`. The created environment will be rendered unusable by the removal of the downloaded wheels."
+        ));
+    }
+    Ok(())
+}
+
 /// Install a set of wheels into a Python virtual environment synchronously.
 #[instrument(skip_all, fields(num_wheels = %wheels.len()))]
 fn install(
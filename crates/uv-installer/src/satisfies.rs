@@ -58,7 +58,7 @@ impl RequirementSatisfaction {
             let extra_build_variables = extra_build_variables_for(name, extra_build_variables);
             let build_info = BuildInfo::from_settings(
                 &config_settings,
-                extra_build_requires,
+                &extra_build_requires,
                 extra_build_variables,
             );
             dist_build_info != &build_info
@@ -397,11 +397,8 @@ fn config_settings_for<'settings>(
 fn extra_build_requires_for<'settings>(
     name: &PackageName,
     extra_build_requires: &'settings ExtraBuildRequires,
-) -> &'settings [ExtraBuildRequirement] {
-    extra_build_requires
-        .get(name)
-        .map(Vec::as_slice)
-        .unwrap_or(&[])
+) -> Cow<'settings, [ExtraBuildRequirement]> {
+    extra_build_requires.for_package(Some(name))
 }
 
 /// Determine the extra build variables for the given package name.
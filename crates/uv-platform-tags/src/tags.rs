@@ -446,6 +446,56 @@ impl Tags {
     pub fn is_cross(&self) -> bool {
         self.is_cross
     }
+
+    /// Return a copy of these tags in which every platform-specific tag is replaced by the given
+    /// [`PlatformTag`], such that only wheels built for that platform (or for any platform) are
+    /// considered compatible.
+    ///
+    /// The Python and ABI tags, and their relative priorities, are preserved.
+    #[must_use]
+    pub fn with_platform_tag(&self, platform_tag: &PlatformTag) -> Self {
+        let mut map: FxHashMap<
+            LanguageTag,
+            FxHashMap<AbiTag, FxHashMap<PlatformTag, TagPriority>>,
+        > = FxHashMap::default();
+        for (python_tag, abi_tags) in self.map.iter() {
+            for (abi_tag, platform_tags) in abi_tags {
+                for (tag, priority) in platform_tags {
+                    let tag = if *tag == PlatformTag::Any {
+                        PlatformTag::Any
+                    } else {
+                        platform_tag.clone()
+                    };
+                    let entry = map
+                        .entry(*python_tag)
+                        .or_default()
+                        .entry(*abi_tag)
+                        .or_default()
+                        .entry(tag)
+                        .or_insert(*priority);
+                    *entry = (*entry).max(*priority);
+                }
+            }
+        }
+
+        let best = self.best.as_ref().map(|(python_tag, abi_tag, tag)| {
+            let tag = if *tag == PlatformTag::Any {
+                PlatformTag::Any
+            } else {
+                platform_tag.clone()
+            };
+            (*python_tag, *abi_tag, tag)
+        });
+
+        Self {
+            map: Arc::new(map),
+            best,
+            python_platform: self.python_platform.clone(),
+            python_version: self.python_version,
+            is_cross: self.is_cross,
+            is_freethreaded: self.is_freethreaded,
+        }
+    }
 }
 
 /// The priority of a platform tag.
@@ -454,6 +504,11 @@ impl Tags {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct TagPriority(NonZeroU32);
 
+impl TagPriority {
+    /// The highest possible priority, used to select a wheel over all other compatible wheels.
+    pub const MAX: Self = Self(NonZeroU32::MAX);
+}
+
 impl TryFrom<usize> for TagPriority {
     type Error = TagsError;
 
@@ -2863,4 +2918,66 @@ mod tests {
         assert!(non_debug_compatibility.is_compatible());
         assert!(debug_compatibility > non_debug_compatibility);
     }
+
+    #[test]
+    fn test_with_platform_tag() {
+        let tags = Tags::from_env(
+            &Platform::new(
+                Os::Manylinux {
+                    major: 2,
+                    minor: 39,
+                },
+                Arch::X86_64,
+            ),
+            (3, 12),
+            "cpython",
+            (3, 12),
+            TagsOptions {
+                manylinux_compatible: true,
+                ..TagsOptions::default()
+            },
+        )
+        .unwrap()
+        .with_platform_tag(&PlatformTag::Manylinux2014 { arch: Arch::X86_64 });
+
+        assert_eq!(
+            tags.compatibility(
+                &[LanguageTag::CPython {
+                    python_version: (3, 12)
+                }],
+                &[AbiTag::CPython {
+                    variant: CPythonAbiVariants::default(),
+                    python_version: (3, 12)
+                }],
+                &[PlatformTag::Manylinux {
+                    major: 2,
+                    minor: 28,
+                    arch: Arch::X86_64
+                }],
+            ),
+            TagCompatibility::Incompatible(IncompatibleTag::Platform)
+        );
+        assert!(tags.is_compatible(
+            &[LanguageTag::CPython {
+                python_version: (3, 12)
+            }],
+            &[AbiTag::CPython {
+                variant: CPythonAbiVariants::default(),
+                python_version: (3, 12)
+            }],
+            &[PlatformTag::Manylinux2014 { arch: Arch::X86_64 }],
+        ));
+        assert!(tags.is_compatible(
+            &[LanguageTag::Python {
+                major: 3,
+                minor: None
+            }],
+            &[AbiTag::None],
+            &[PlatformTag::Any],
+        ));
+        assert_eq!(
+            tags.platform_tag(),
+            Some(&PlatformTag::Manylinux2014 { arch: Arch::X86_64 })
+        );
+    }
 }
@@ -0,0 +1,192 @@
+use crate::MetadataError;
+use crate::metadata::Headers;
+
+/// Trove classifiers that identify a single license unambiguously, with the corresponding SPDX
+/// identifier.
+///
+/// Classifiers that cover several licenses (e.g., `License :: OSI Approved :: BSD License`) are
+/// omitted, since the SPDX identifier can't be inferred from them.
+const LICENSE_CLASSIFIERS: &[(&str, &str)] = &[
+    (
+        "License :: OSI Approved :: Boost Software License 1.0 (BSL-1.0)",
+        "BSL-1.0",
+    ),
+    (
+        "License :: OSI Approved :: Eclipse Public License 2.0 (EPL-2.0)",
+        "EPL-2.0",
+    ),
+    (
+        "License :: OSI Approved :: GNU Affero General Public License v3",
+        "AGPL-3.0-only",
+    ),
+    (
+        "License :: OSI Approved :: GNU Affero General Public License v3 or later (AGPLv3+)",
+        "AGPL-3.0-or-later",
+    ),
+    (
+        "License :: OSI Approved :: GNU General Public License v2 (GPLv2)",
+        "GPL-2.0-only",
+    ),
+    (
+        "License :: OSI Approved :: GNU General Public License v2 or later (GPLv2+)",
+        "GPL-2.0-or-later",
+    ),
+    (
+        "License :: OSI Approved :: GNU General Public License v3 (GPLv3)",
+        "GPL-3.0-only",
+    ),
+    (
+        "License :: OSI Approved :: GNU General Public License v3 or later (GPLv3+)",
+        "GPL-3.0-or-later",
+    ),
+    (
+        "License :: OSI Approved :: GNU Lesser General Public License v2 (LGPLv2)",
+        "LGPL-2.0-only",
+    ),
+    (
+        "License :: OSI Approved :: GNU Lesser General Public License v2 or later (LGPLv2+)",
+        "LGPL-2.0-or-later",
+    ),
+    (
+        "License :: OSI Approved :: GNU Lesser General Public License v3 (LGPLv3)",
+        "LGPL-3.0-only",
+    ),
+    (
+        "License :: OSI Approved :: GNU Lesser General Public License v3 or later (LGPLv3+)",
+        "LGPL-3.0-or-later",
+    ),
+    ("License :: OSI Approved :: ISC License (ISCL)", "ISC"),
+    ("License :: OSI Approved :: MIT License", "MIT"),
+    (
+        "License :: OSI Approved :: MIT No Attribution License (MIT-0)",
+        "MIT-0",
+    ),
+    (
+        "License :: OSI Approved :: Mozilla Public License 2.0 (MPL 2.0)",
+        "MPL-2.0",
+    ),
+    (
+        "License :: OSI Approved :: Python Software Foundation License",
+        "PSF-2.0",
+    ),
+    (
+        "License :: OSI Approved :: The Unlicense (Unlicense)",
+        "Unlicense",
+    ),
+    (
+        "License :: OSI Approved :: Universal Permissive License (UPL)",
+        "UPL-1.0",
+    ),
+    ("License :: OSI Approved :: zlib/libpng License", "Zlib"),
+];
+
+/// Determine the SPDX license expression of a distribution from its core metadata (e.g., the
+/// `METADATA` file of a wheel), if it can be determined unambiguously.
+///
+/// In order of precedence, the license is read from:
+///
+/// 1. The `License-Expression` field (Metadata 2.4).
+/// 2. The `License` field, if it's a valid SPDX expression (e.g., `MIT`), rather than free-form
+///    text.
+/// 3. The `License ::` trove classifiers, if there's exactly one, and it maps to a single SPDX
+///    identifier.
+pub fn parse_license(content: &[u8]) -> Result<Option<String>, MetadataError> {
+    let headers = Headers::parse(content)?;
+    Ok(license_from_headers(&headers))
+}
+
+fn license_from_headers(headers: &Headers) -> Option<String> {
+    if let Some(expression) = headers.get_first_value("License-Expression") {
+        return spdx_expression(&expression);
+    }
+
+    if let Some(expression) = headers
+        .get_first_value("License")
+        .and_then(|license| spdx_expression(&license))
+    {
+        return Some(expression);
+    }
+
+    let mut classifiers = headers
+        .get_all_values("Classifier")
+        .filter(|classifier| classifier.starts_with("License ::"));
+    let classifier = classifiers.next()?;
+    if classifiers.next().is_some() {
+        return None;
+    }
+    LICENSE_CLASSIFIERS
+        .iter()
+        .find(|(name, _)| *name == classifier.trim())
+        .map(|(_, id)| (*id).to_string())
+}
+
+/// Validate a string as a strict SPDX license expression.
+///
+/// Imprecise names (e.g., `BSD`) are rejected, rather than guessed.
+fn spdx_expression(expression: &str) -> Option<String> {
+    let expression = expression.trim();
+    spdx::Expression::parse_mode(expression, spdx::ParseMode::STRICT)
+        .ok()
+        .map(|_| expression.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_license;
+
+    fn license(metadata: &str) -> Option<String> {
+        parse_license(metadata.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn license_expression() {
+        assert_eq!(
+            license("Name: foo\nLicense-Expression: MIT OR Apache-2.0\nLicense: BSD\n"),
+            Some("MIT OR Apache-2.0".to_string())
+        );
+        // An invalid expression isn't overridden by the other fields.
+        assert_eq!(
+            license(
+                "Name: foo\nLicense-Expression: Apache 2\nClassifier: License :: OSI Approved :: MIT License\n"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn license_field() {
+        assert_eq!(
+            license("Name: foo\nLicense: BSD-3-Clause\n"),
+            Some("BSD-3-Clause".to_string())
+        );
+        // Free-form text and imprecise names aren't SPDX expressions.
+        assert_eq!(license("Name: foo\nLicense: BSD\n"), None);
+        assert_eq!(
+            license("Name: foo\nLicense: Copyright (c) Foo. All rights reserved.\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn license_classifiers() {
+        assert_eq!(
+            license(
+                "Name: foo\nLicense: Apache 2.0\nClassifier: License :: OSI Approved :: MIT License\n"
+            ),
+            Some("MIT".to_string())
+        );
+        // Classifiers that cover several licenses are ambiguous.
+        assert_eq!(
+            license("Name: foo\nClassifier: License :: OSI Approved :: BSD License\n"),
+            None
+        );
+        // So are multiple license classifiers.
+        assert_eq!(
+            license(
+                "Name: foo\nClassifier: License :: OSI Approved :: MIT License\nClassifier: License :: OSI Approved :: Apache Software License\n"
+            ),
+            None
+        );
+        assert_eq!(license("Name: foo\n"), None);
+    }
+}
@@ -957,7 +957,8 @@ pub fn create_link_to_executable(link: &Path, executable: &Path) -> Result<(), E
 
 /// Create or replace a link to a managed Python executable.
 ///
-/// If a file already exists at the link path, it will be atomically replaced.
+/// If a file already exists at the link path, it will be atomically replaced. On Windows, an
+/// existing Python launcher is instead updated in place to point to the new executable.
 ///
 /// See [`create_link_to_executable`] for a variant that errors if the link already exists.
 pub fn replace_link_to_executable(link: &Path, executable: &Path) -> Result<(), Error> {
@@ -969,6 +970,16 @@ pub fn replace_link_to_executable(link: &Path, executable: &Path) -> Result<(),
     } else if cfg!(windows) {
         use uv_trampoline_builder::windows_python_launcher;
 
+        // If the link is already a Python launcher, point it at the new executable. Launchers
+        // created by an older version of uv are rewritten in full rather than in place.
+        if Launcher::try_from_path(link)
+            .ok()
+            .flatten()
+            .is_some_and(|launcher| matches!(launcher.kind, LauncherKind::Python))
+        {
+            return Ok(Launcher::retarget(link, executable)?);
+        }
+
         let launcher = windows_python_launcher(executable, false)?;
 
         uv_fs::write_atomic_sync(link, &*launcher).map_err(Error::LinkExecutable)
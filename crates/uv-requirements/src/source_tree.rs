@@ -1,3 +1,4 @@
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
@@ -54,6 +55,9 @@ pub struct SourceTreeResolution {
     pub project: PackageName,
     /// The extras used when resolving the requirements.
     pub extras: Box<[ExtraName]>,
+    /// The enabled extras that request each active requirement. The set is empty for requirements
+    /// that are requested without an extra.
+    pub requirement_extras: BTreeMap<PackageName, BTreeSet<ExtraName>>,
 }
 
 /// A resolver for requirements specified via source trees.
@@ -123,18 +127,46 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
             .collect::<Vec<_>>();
 
         let mut requirements = Vec::new();
+        let mut requirement_extras = BTreeMap::<PackageName, BTreeSet<ExtraName>>::new();
+        let mut base_requirements = BTreeSet::new();
 
         // Flatten any transitive extras and include dependencies
         // (unless something like --only-group was passed)
-        requirements.extend(
+        for requirement in
             FlatRequiresDist::from_requirements(metadata.requires_dist, &metadata.name)
-                .into_iter()
-                .map(|requirement| Requirement {
-                    origin: Some(origin.clone()),
-                    marker: requirement.marker.simplify_extras(&extras),
-                    ..requirement
-                }),
-        );
+        {
+            // Track the enabled extras that request the requirement. A requirement that is only
+            // requested by a combination of extras is treated as if it were requested without one.
+            if requirement.marker.evaluate_extras(&[]) {
+                base_requirements.insert(requirement.name.clone());
+            } else if requirement.marker.evaluate_extras(&extras) {
+                let requested_by = extras
+                    .iter()
+                    .filter(|extra| {
+                        requirement
+                            .marker
+                            .evaluate_extras(std::slice::from_ref(extra))
+                    })
+                    .cloned()
+                    .collect::<BTreeSet<_>>();
+                if requested_by.is_empty() {
+                    base_requirements.insert(requirement.name.clone());
+                }
+                requirement_extras
+                    .entry(requirement.name.clone())
+                    .or_default()
+                    .extend(requested_by);
+            }
+
+            requirements.push(Requirement {
+                origin: Some(origin.clone()),
+                marker: requirement.marker.simplify_extras(&extras),
+                ..requirement
+            });
+        }
+        for name in base_requirements {
+            requirement_extras.insert(name, BTreeSet::new());
+        }
 
         let requirements = requirements.into_boxed_slice();
         let project = metadata.name;
@@ -144,6 +176,7 @@ impl<'a, Context: BuildContext> SourceTreeResolver<'a, Context> {
             requirements,
             project,
             extras,
+            requirement_extras,
         })
     }
 
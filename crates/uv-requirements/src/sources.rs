@@ -19,6 +19,12 @@ pub enum RequirementsSource {
     PylockToml(PathBuf),
     /// Dependencies were provided via a `requirements.txt` file (e.g., `pip install -r requirements.txt`).
     RequirementsTxt(PathBuf),
+    /// Constraints were provided via a TOML file with a `[constraints]` table (e.g.,
+    /// `pip install -c constraints.toml`).
+    ConstraintsToml(PathBuf),
+    /// Overrides were provided via a TOML file with an `override-dependencies` array (e.g.,
+    /// `pip compile --dependency-overrides-file overrides.toml`).
+    OverridesToml(PathBuf),
     /// Dependencies were provided via a `pyproject.toml` file (e.g., `pip-compile pyproject.toml`).
     PyprojectToml(PathBuf),
     /// Dependencies were provided via a `setup.py` file (e.g., `pip-compile setup.py`).
@@ -140,14 +146,30 @@ impl RequirementsSource {
             .extension()
             .is_some_and(|ext| ext.eq_ignore_ascii_case("toml"))
         {
-            return Err(anyhow::anyhow!(
-                "The file `{}` appears to be a TOML file, but constraints must be specified in `requirements.txt` format",
-                path.user_display(),
-            ));
+            return Ok(Self::ConstraintsToml(path));
         }
         Ok(Self::RequirementsTxt(path))
     }
 
+    /// Parse a [`RequirementsSource`] from a `constraints.toml` file, regardless of its extension.
+    pub fn from_constraints_toml(path: PathBuf) -> Result<Self> {
+        for file_name in ["pyproject.toml", "setup.py", "setup.cfg"] {
+            if path.ends_with(file_name) {
+                return Err(anyhow::anyhow!(
+                    "The file `{}` appears to be a `{}` file, but constraints must be specified in a `[constraints]` table",
+                    path.user_display(),
+                    file_name
+                ));
+            }
+        }
+        Ok(Self::ConstraintsToml(path))
+    }
+
+    /// Parse a [`RequirementsSource`] from an overrides TOML file, regardless of its extension.
+    pub fn from_overrides_toml(path: PathBuf) -> Self {
+        Self::OverridesToml(path)
+    }
+
     /// Parse a [`RequirementsSource`] from an `overrides.txt` file.
     pub fn from_overrides_txt(path: PathBuf) -> Result<Self> {
         if path == Path::new("-") {
@@ -321,6 +343,8 @@ impl std::fmt::Display for RequirementsSource {
             Self::Editable(path) => write!(f, "-e {path:?}"),
             Self::PylockToml(path)
             | Self::RequirementsTxt(path)
+            | Self::ConstraintsToml(path)
+            | Self::OverridesToml(path)
             | Self::Pep723Script(path)
             | Self::PyprojectToml(path)
             | Self::SetupPy(path)
@@ -29,6 +29,7 @@
 
 use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 use anyhow::{Context, Result};
 use rustc_hash::FxHashSet;
@@ -38,14 +39,16 @@ use url::Url;
 use uv_cache_key::CanonicalUrl;
 use uv_client::BaseClientBuilder;
 use uv_configuration::{DependencyGroups, NoBinary, NoBuild};
-use uv_distribution_types::{Index, Requirement};
+use uv_distribution_types::{Index, Requirement, RequirementSource};
 use uv_distribution_types::{
     IndexUrl, NameRequirementSpecification, UnresolvedRequirement,
     UnresolvedRequirementSpecification,
 };
 use uv_fs::{CWD, Simplified};
 use uv_normalize::{ExtraName, PackageName, PipGroupName};
-use uv_pypi_types::PyProjectToml;
+use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+use uv_pep508::{MarkerTree, RequirementOrigin};
+use uv_pypi_types::{PyProjectToml, VerbatimParsedUrl};
 use uv_redacted::DisplaySafeUrl;
 use uv_requirements_txt::{RequirementsTxt, RequirementsTxtRequirement, SourceCache};
 use uv_scripts::Pep723Metadata;
@@ -233,6 +236,110 @@ impl RequirementsSpecification {
         }
     }
 
+    /// Create a [`RequirementsSpecification`] from the contents of a `constraints.toml` file.
+    ///
+    /// Each entry in the `[constraints]` table maps a package name to either a version (e.g.,
+    /// `numpy = "1.26.4"`), which pins the package to that version, or a version specifier (e.g.,
+    /// `numpy = ">=1.24,<2"`).
+    fn from_constraints_toml(content: &str, path: &Path) -> Result<Self> {
+        let table = toml::from_str::<toml::Table>(content)?;
+        let Some(entries) = table.get("constraints") else {
+            return Err(anyhow::anyhow!("Missing `[constraints]` table"));
+        };
+        let Some(entries) = entries.as_table() else {
+            return Err(anyhow::anyhow!("Expected `constraints` to be a table"));
+        };
+
+        let constraints = entries
+            .iter()
+            .map(|(key, value)| {
+                let name = PackageName::from_str(key)
+                    .with_context(|| format!("`{key}` is not a valid package name"))?;
+                let Some(value) = value.as_str() else {
+                    return Err(anyhow::anyhow!(
+                        "Expected the constraint for `{key}` to be a string"
+                    ));
+                };
+                let value = value.trim();
+                let specifier = if let Ok(version) = Version::from_str(value) {
+                    VersionSpecifiers::from(VersionSpecifier::equals_version(version))
+                } else {
+                    VersionSpecifiers::from_str(value).with_context(|| {
+                        format!("`{value}` is not a valid version or version specifier for `{key}`")
+                    })?
+                };
+                Ok(NameRequirementSpecification::from(Requirement {
+                    name,
+                    extras: Box::new([]),
+                    groups: Box::new([]),
+                    marker: MarkerTree::default(),
+                    source: RequirementSource::Registry {
+                        specifier,
+                        index: None,
+                        conflict: None,
+                    },
+                    origin: Some(RequirementOrigin::File(path.to_path_buf())),
+                }))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            constraints,
+            ..Self::default()
+        })
+    }
+
+    /// Parse the overrides from the contents of an overrides TOML file.
+    ///
+    /// The overrides are read from an `override-dependencies` array, as in a `uv.toml`, or from
+    /// `tool.uv.override-dependencies`, as in a `pyproject.toml`.
+    fn from_overrides_toml(content: &str, path: &Path) -> Result<Self> {
+        let table = toml::from_str::<toml::Table>(content)?;
+        let entries = table.get("override-dependencies").or_else(|| {
+            table
+                .get("tool")
+                .and_then(|tool| tool.get("uv"))
+                .and_then(|uv| uv.get("override-dependencies"))
+        });
+        let Some(entries) = entries else {
+            return Err(anyhow::anyhow!("Missing `override-dependencies` array"));
+        };
+        let Some(entries) = entries.as_array() else {
+            return Err(anyhow::anyhow!(
+                "Expected `override-dependencies` to be an array"
+            ));
+        };
+
+        // Resolve relative paths against the directory containing the file.
+        let working_dir = path
+            .parent()
+            .filter(|_| path.exists())
+            .unwrap_or(CWD.as_path());
+
+        let overrides = entries
+            .iter()
+            .map(|entry| {
+                let Some(entry) = entry.as_str() else {
+                    return Err(anyhow::anyhow!(
+                        "Expected each entry in `override-dependencies` to be a string"
+                    ));
+                };
+                let requirement =
+                    uv_pep508::Requirement::<VerbatimParsedUrl>::parse(entry, working_dir)
+                        .with_context(|| format!("`{entry}` is not a valid requirement"))?;
+                Ok(UnresolvedRequirementSpecification::from(
+                    Requirement::from(requirement)
+                        .with_origin(RequirementOrigin::File(path.to_path_buf())),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            overrides,
+            ..Self::default()
+        })
+    }
+
     /// Read the requirements and constraints from a source, using a cache for file contents.
     #[instrument(skip_all, level = tracing::Level::DEBUG, fields(source = % source))]
     pub async fn from_source_with_cache(
@@ -270,6 +377,34 @@ impl RequirementsSpecification {
 
                 Self::from_requirements_txt(requirements_txt)
             }
+            RequirementsSource::ConstraintsToml(path) => {
+                let content = read_cached_file(path, client_builder, cache).await?;
+                let specification = Self::from_constraints_toml(&content, path)
+                    .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
+
+                if specification.constraints.is_empty() {
+                    warn_user!(
+                        "Constraints file `{}` does not contain any constraints",
+                        path.user_display()
+                    );
+                }
+
+                specification
+            }
+            RequirementsSource::OverridesToml(path) => {
+                let content = read_cached_file(path, client_builder, cache).await?;
+                let specification = Self::from_overrides_toml(&content, path)
+                    .with_context(|| format!("Failed to parse: `{}`", path.user_display()))?;
+
+                if specification.overrides.is_empty() {
+                    warn_user!(
+                        "Overrides file `{}` does not contain any overrides",
+                        path.user_display()
+                    );
+                }
+
+                specification
+            }
             RequirementsSource::PyprojectToml(path) => {
                 let content = match fs_err::tokio::read_to_string(&path).await {
                     Ok(content) => content,
@@ -771,6 +906,28 @@ pub struct GroupsSpecification {
     pub groups: Vec<PipGroupName>,
 }
 
+/// Read the contents of a file, a URL, or `-` for stdin, using a cache for file contents.
+async fn read_cached_file(
+    path: &Path,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: &mut SourceCache,
+) -> Result<String> {
+    if !(path == Path::new("-")
+        || path.starts_with("http://")
+        || path.starts_with("https://")
+        || path.exists())
+    {
+        return Err(anyhow::anyhow!("File not found: `{}`", path.user_display()));
+    }
+
+    if let Some(content) = cache.get(path) {
+        return Ok(content.clone());
+    }
+    let content = read_file(path, client_builder).await?;
+    cache.insert(path.to_path_buf(), content.clone());
+    Ok(content)
+}
+
 /// Read the contents of a path, fetching over HTTP(S) if necessary.
 async fn read_file(path: &Path, client_builder: &BaseClientBuilder<'_>) -> Result<String> {
     // If the path is a URL, fetch it over HTTP(S).
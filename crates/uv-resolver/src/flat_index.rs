@@ -13,10 +13,12 @@ use uv_distribution_types::{
 };
 use uv_normalize::PackageName;
 use uv_pep440::Version;
-use uv_platform_tags::{TagCompatibility, Tags};
+use uv_platform_tags::{TagCompatibility, TagPriority, Tags};
 use uv_pypi_types::HashDigest;
 use uv_types::HashStrategy;
 
+use crate::WheelTags;
+
 /// A set of [`PrioritizedDist`] from a `--find-links` entry, indexed by [`PackageName`]
 /// and [`Version`].
 #[derive(Debug, Clone, Default)]
@@ -36,6 +38,7 @@ impl FlatIndex {
         tags: Option<&Tags>,
         hasher: &HashStrategy,
         build_options: &BuildOptions,
+        wheel_tags: &WheelTags,
     ) -> Self {
         // Collect compatible distributions.
         let mut index = FxHashMap::<PackageName, FlatDistributions>::default();
@@ -47,6 +50,7 @@ impl FlatIndex {
                 tags,
                 hasher,
                 build_options,
+                wheel_tags,
                 entry.index,
             );
         }
@@ -82,6 +86,7 @@ impl FlatDistributions {
         tags: Option<&Tags>,
         hasher: &HashStrategy,
         build_options: &BuildOptions,
+        wheel_tags: &WheelTags,
     ) -> Self {
         let mut distributions = Self::default();
         for entry in entries {
@@ -91,6 +96,7 @@ impl FlatDistributions {
                 tags,
                 hasher,
                 build_options,
+                wheel_tags,
                 entry.index,
             );
         }
@@ -115,6 +121,7 @@ impl FlatDistributions {
         tags: Option<&Tags>,
         hasher: &HashStrategy,
         build_options: &BuildOptions,
+        wheel_tags: &WheelTags,
         index: IndexUrl,
     ) {
         // No `requires-python` here: for source distributions, we don't have that information;
@@ -129,6 +136,7 @@ impl FlatDistributions {
                     tags,
                     hasher,
                     build_options,
+                    wheel_tags,
                 );
                 let dist = RegistryBuiltWheel {
                     filename,
@@ -205,6 +213,7 @@ impl FlatDistributions {
         tags: Option<&Tags>,
         hasher: &HashStrategy,
         build_options: &BuildOptions,
+        wheel_tags: &WheelTags,
     ) -> WheelCompatibility {
         // Check if binaries are allowed for this package.
         if build_options.no_binary_package(&filename.name) {
@@ -217,6 +226,9 @@ impl FlatDistributions {
                 TagCompatibility::Incompatible(tag) => {
                     return WheelCompatibility::Incompatible(IncompatibleWheel::Tag(tag));
                 }
+                TagCompatibility::Compatible(_) if wheel_tags.prefers(filename) => {
+                    Some(TagPriority::MAX)
+                }
                 TagCompatibility::Compatible(priority) => Some(priority),
             },
             None => None,
@@ -19,7 +19,8 @@ pub use preferences::{Preference, PreferenceError, Preferences};
 pub use prerelease::PrereleaseMode;
 pub use python_requirement::PythonRequirement;
 pub use resolution::{
-    AnnotationStyle, ConflictingDistributionError, DisplayResolutionGraph, ResolverOutput,
+    AnnotationStyle, ConflictingDistributionError, DisplayResolutionConstraints,
+    DisplayResolutionGraph, DisplayResolutionTree, ResolverOutput,
 };
 pub use resolution_mode::ResolutionMode;
 pub use resolver::{
@@ -31,6 +32,7 @@ pub use universal_marker::{ConflictMarker, UniversalMarker};
 pub use upgrade::UpgradePackages;
 pub use uv_distribution_types::{ExcludeNewerOverride, ExcludeNewerSpan, ExcludeNewerValue};
 pub use version_map::VersionMap;
+pub use wheel_tags::{WheelTag, WheelTagEntry, WheelTags};
 pub use yanks::AllowedYanks;
 
 /// A custom `HashSet` using `hashbrown`.
@@ -69,4 +71,5 @@ mod resolver;
 mod universal_marker;
 mod upgrade;
 mod version_map;
+mod wheel_tags;
 mod yanks;
@@ -3,7 +3,7 @@ use uv_pypi_types::SupportedEnvironments;
 use uv_torch::TorchStrategy;
 
 use crate::fork_strategy::ForkStrategy;
-use crate::{DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionMode};
+use crate::{DependencyMode, ExcludeNewer, PrereleaseMode, ResolutionMode, WheelTags};
 
 /// Options for resolving a manifest.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -18,6 +18,7 @@ pub struct Options {
     pub flexibility: Flexibility,
     pub build_options: BuildOptions,
     pub torch_backend: Option<TorchStrategy>,
+    pub wheel_tags: WheelTags,
 }
 
 /// Builder for [`Options`].
@@ -33,6 +34,7 @@ pub struct OptionsBuilder {
     flexibility: Flexibility,
     build_options: BuildOptions,
     torch_backend: Option<TorchStrategy>,
+    wheel_tags: WheelTags,
 }
 
 impl OptionsBuilder {
@@ -111,6 +113,13 @@ impl OptionsBuilder {
         self
     }
 
+    /// Sets the [`WheelTags`] to select over other compatible wheels.
+    #[must_use]
+    pub fn wheel_tags(mut self, wheel_tags: WheelTags) -> Self {
+        self.wheel_tags = wheel_tags;
+        self
+    }
+
     /// Builds the options.
     pub fn build(self) -> Options {
         Options {
@@ -124,6 +133,7 @@ impl OptionsBuilder {
             flexibility: self.flexibility,
             build_options: self.build_options,
             torch_backend: self.torch_backend,
+            wheel_tags: self.wheel_tags,
         }
     }
 }
@@ -1,12 +1,17 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 
+use itertools::Itertools;
 use owo_colors::OwoColorize;
+use petgraph::graph::NodeIndex;
 use petgraph::visit::EdgeRef;
 use petgraph::{Directed, Direction, Graph};
-use rustc_hash::{FxBuildHasher, FxHashMap};
+use rustc_hash::{FxBuildHasher, FxHashMap, FxHashSet};
 
-use uv_distribution_types::{DistributionMetadata, Name, SourceAnnotation, SourceAnnotations};
-use uv_normalize::PackageName;
+use uv_distribution_types::{
+    DistributionMetadata, Name, RequirementSource, SourceAnnotation, SourceAnnotations,
+};
+use uv_normalize::{ExtraName, PackageName};
+use uv_pep440::Version;
 use uv_pep508::MarkerTree;
 
 use crate::resolution::{RequirementsTxtDist, ResolutionGraphNode};
@@ -21,6 +26,9 @@ pub struct DisplayResolutionGraph<'a> {
     env: &'a ResolverEnvironment,
     /// The packages to exclude from the output.
     no_emit_packages: &'a [PackageName],
+    /// Whether to exclude transitive dependencies from the output, retaining only the packages
+    /// that were requested directly.
+    direct_only: bool,
     /// Whether to include hashes in the output.
     show_hashes: bool,
     /// Whether to include extras in the output (e.g., `black[colorama]`).
@@ -32,6 +40,19 @@ pub struct DisplayResolutionGraph<'a> {
     include_annotations: bool,
     /// Whether to include indexes in the output, to indicate which index was used for each package.
     include_index_annotation: bool,
+    /// Whether to include the download URL of the distribution selected for each package in the
+    /// output.
+    include_package_urls: bool,
+    /// If set, include the SPDX license expression of each package in the output. Maps each
+    /// package and version to its license; packages that are absent are shown as `UNKNOWN`.
+    licenses: Option<&'a BTreeMap<PackageName, BTreeMap<Version, String>>>,
+    /// Whether to include annotations in the output, to indicate the extras through which each
+    /// package was resolved (e.g., `# extras: security`).
+    include_extras_annotation: bool,
+    /// If set, group the output into sections by the extras that request each package. Maps each
+    /// direct requirement to the enabled extras that request it; the set is empty for
+    /// requirements that are requested without an extra.
+    extra_groups: Option<&'a BTreeMap<PackageName, BTreeSet<ExtraName>>>,
     /// The style of annotation comments, used to indicate the dependencies that requested each
     /// package.
     annotation_style: AnnotationStyle,
@@ -55,11 +76,16 @@ impl<'a> DisplayResolutionGraph<'a> {
         underlying: &'a ResolverOutput,
         env: &'a ResolverEnvironment,
         no_emit_packages: &'a [PackageName],
+        direct_only: bool,
         show_hashes: bool,
         include_extras: bool,
         include_markers: bool,
         include_annotations: bool,
         include_index_annotation: bool,
+        include_package_urls: bool,
+        licenses: Option<&'a BTreeMap<PackageName, BTreeMap<Version, String>>>,
+        include_extras_annotation: bool,
+        extra_groups: Option<&'a BTreeMap<PackageName, BTreeSet<ExtraName>>>,
         annotation_style: AnnotationStyle,
     ) -> Self {
         for fork_marker in &underlying.fork_markers {
@@ -73,11 +99,16 @@ impl<'a> DisplayResolutionGraph<'a> {
             resolution: underlying,
             env,
             no_emit_packages,
+            direct_only,
             show_hashes,
             include_extras,
             include_markers,
             include_annotations,
             include_index_annotation,
+            include_package_urls,
+            licenses,
+            include_extras_annotation,
+            extra_groups,
             annotation_style,
         }
     }
@@ -138,25 +169,27 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
             SourceAnnotations::default()
         };
 
-        // Convert a [`petgraph::graph::Graph`] based on [`ResolutionGraphNode`] to a graph based on
-        // [`DisplayResolutionGraphNode`]. In other words: converts from [`AnnotatedDist`] to
-        // [`RequirementsTxtDist`].
-        //
-        // We assign each package its propagated markers: In `requirements.txt`, we want a flat list
-        // that for each package tells us if it should be installed on the current platform, without
-        // looking at which packages depend on it.
-        let graph = self.resolution.graph.map(
-            |_index, node| match node {
-                ResolutionGraphNode::Root => DisplayResolutionGraphNode::Root,
-                ResolutionGraphNode::Dist(dist) => {
-                    let dist = RequirementsTxtDist::from_annotated_dist(dist);
-                    DisplayResolutionGraphNode::Dist(dist)
+        let graph = to_intermediate_graph(self.resolution);
+
+        // Determine the extras through which each package was resolved, before they're removed
+        // or combined below.
+        let resolved_extras = if self.include_extras_annotation {
+            let mut resolved_extras = FxHashMap::<&PackageName, BTreeSet<&ExtraName>>::default();
+            for node in graph.node_weights() {
+                let DisplayResolutionGraphNode::Dist(dist) = node else {
+                    continue;
+                };
+                if !dist.extras.is_empty() {
+                    resolved_extras
+                        .entry(dist.name())
+                        .or_default()
+                        .extend(&dist.extras);
                 }
-            },
-            // We can drop the edge markers, while retaining their existence and direction for the
-            // annotations.
-            |_index, _edge| (),
-        );
+            }
+            resolved_extras
+        } else {
+            FxHashMap::default()
+        };
 
         // Reduce the graph, removing or combining extras for a given package.
         let graph = if self.include_extras {
@@ -165,6 +198,20 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
             strip_extras(&graph)
         };
 
+        // Determine the packages that were requested directly.
+        let direct = if self.direct_only {
+            self.resolution
+                .requirements
+                .iter()
+                .filter(|requirement| {
+                    requirement.evaluate_markers(self.env.marker_environment(), &[])
+                })
+                .map(|requirement| &requirement.name)
+                .collect::<FxHashSet<_>>()
+        } else {
+            FxHashSet::default()
+        };
+
         // Collect all packages.
         let mut nodes = graph
             .node_indices()
@@ -174,6 +221,9 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                 if self.no_emit_packages.contains(name) {
                     return None;
                 }
+                if self.direct_only && !direct.contains(name) {
+                    return None;
+                }
 
                 Some((index, dist))
             })
@@ -182,6 +232,101 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
         // Sort the nodes by name, but with editable packages first.
         nodes.sort_unstable_by_key(|(index, node)| (node.to_comparator(), *index));
 
+        // If requested, group the nodes by the extras that request them. Packages that are
+        // requested without an extra come first, in a section without a header.
+        let sections = if let Some(extra_groups) = self.extra_groups {
+            let node_extras = self.node_extras(&graph, extra_groups);
+            let mut sections = BTreeMap::<Vec<&ExtraName>, Vec<_>>::new();
+            for (index, node) in nodes {
+                let extras = node_extras
+                    .get(&index)
+                    .map(|extras| extras.iter().copied().collect())
+                    .unwrap_or_default();
+                sections.entry(extras).or_default().push((index, node));
+            }
+            sections
+        } else {
+            BTreeMap::from([(Vec::new(), nodes)])
+        };
+
+        for (extras, nodes) in sections {
+            if !extras.is_empty() {
+                writeln!(f)?;
+                writeln!(
+                    f,
+                    "{}",
+                    format!("# [extra: {}]", extras.iter().join(", ")).green()
+                )?;
+            }
+            self.write_nodes(f, &graph, &sources, &resolved_extras, nodes)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl DisplayResolutionGraph<'_> {
+    /// Determine the extras that request each node, for nodes that are only reachable from
+    /// requirements that are requested via an extra.
+    fn node_extras<'graph>(
+        &self,
+        graph: &RequirementsTxtGraph<'_>,
+        extra_groups: &'graph BTreeMap<PackageName, BTreeSet<ExtraName>>,
+    ) -> FxHashMap<NodeIndex, BTreeSet<&'graph ExtraName>> {
+        // For each node, whether it's reachable from a requirement without an extra, and the
+        // extras from which it's reachable otherwise.
+        let mut labels = FxHashMap::<NodeIndex, (bool, BTreeSet<&ExtraName>)>::default();
+        let mut queue = Vec::new();
+
+        let direct = self
+            .resolution
+            .requirements
+            .iter()
+            .filter(|requirement| requirement.evaluate_markers(self.env.marker_environment(), &[]))
+            .map(|requirement| &requirement.name)
+            .collect::<FxHashSet<_>>();
+        for index in graph.node_indices() {
+            let name = graph[index].name();
+            if !direct.contains(name) {
+                continue;
+            }
+            let label = labels.entry(index).or_default();
+            match extra_groups.get(name) {
+                Some(extras) if !extras.is_empty() => label.1.extend(extras),
+                _ => label.0 = true,
+            }
+            queue.push(index);
+        }
+
+        // Propagate the labels to the transitive dependencies.
+        while let Some(index) = queue.pop() {
+            let (base, extras) = labels[&index].clone();
+            for dependency in graph.neighbors_directed(index, Direction::Outgoing) {
+                let label = labels.entry(dependency).or_default();
+                if (base && !label.0) || !extras.is_subset(&label.1) {
+                    label.0 |= base;
+                    label.1.extend(&extras);
+                    queue.push(dependency);
+                }
+            }
+        }
+
+        labels
+            .into_iter()
+            .filter(|(_, (base, _))| !base)
+            .map(|(index, (_, extras))| (index, extras))
+            .collect()
+    }
+
+    /// Write the given nodes, along with their hashes and annotations.
+    fn write_nodes(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        graph: &RequirementsTxtGraph<'_>,
+        sources: &SourceAnnotations,
+        resolved_extras: &FxHashMap<&PackageName, BTreeSet<&ExtraName>>,
+        nodes: Vec<(NodeIndex, &RequirementsTxtDist<'_>)>,
+    ) -> std::fmt::Result {
         // Print out the dependency graph.
         for (index, node) in nodes {
             // Display the node itself.
@@ -293,12 +438,237 @@ impl std::fmt::Display for DisplayResolutionGraph<'_> {
                     writeln!(f, "{}", format!("    # from {url}").green())?;
                 }
             }
+
+            // If enabled, include the URL of the distribution selected for each package (e.g.,
+            // `# url: https://files.pythonhosted.org/...`).
+            if self.include_package_urls {
+                if let Some(url) = node.dist.file().and_then(|file| file.url.to_url().ok()) {
+                    let url = url.without_credentials();
+                    writeln!(f, "{}", format!("    # url: {url}").green())?;
+                }
+            }
+
+            // If enabled, include the license of each package (e.g., `# license: MIT`), or
+            // `UNKNOWN` if it isn't a valid SPDX expression, or is otherwise ambiguous.
+            if let Some(licenses) = self.licenses {
+                let license = licenses
+                    .get(node.name())
+                    .and_then(|versions| versions.get(node.version))
+                    .map_or("UNKNOWN", String::as_str);
+                writeln!(f, "{}", format!("    # license: {license}").green())?;
+            }
+
+            // If enabled, include the extras through which each package was resolved (e.g.,
+            // `# extras: security`).
+            if self.include_extras_annotation {
+                if let Some(extras) = resolved_extras.get(node.name()) {
+                    let extras = extras.iter().join(", ");
+                    writeln!(f, "{}", format!("    # extras: {extras}").green())?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A [`std::fmt::Display`] implementation for the resolution graph, in the format of a
+/// constraints file.
+///
+/// Each package is written as a pin (e.g., `{name}=={version}`), without extras, hashes, or
+/// annotations. Editable and local packages are omitted, as they can't be used as constraints.
+#[derive(Debug)]
+pub struct DisplayResolutionConstraints<'a> {
+    /// The underlying graph.
+    resolution: &'a ResolverOutput,
+    /// The packages to exclude from the output.
+    no_emit_packages: &'a [PackageName],
+    /// Whether to include environment markers in the output (e.g., `black ; sys_platform == "win32"`).
+    include_markers: bool,
+}
+
+impl<'a> DisplayResolutionConstraints<'a> {
+    /// Create a new [`DisplayResolutionConstraints`] for the given graph.
+    pub fn new(
+        underlying: &'a ResolverOutput,
+        no_emit_packages: &'a [PackageName],
+        include_markers: bool,
+    ) -> Self {
+        Self {
+            resolution: underlying,
+            no_emit_packages,
+            include_markers,
+        }
+    }
+}
+
+impl std::fmt::Display for DisplayResolutionConstraints<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let graph = strip_extras(&to_intermediate_graph(self.resolution));
+
+        // Collect all packages that can be expressed as constraints.
+        let mut nodes = graph
+            .node_weights()
+            .filter(|dist| !self.no_emit_packages.contains(dist.name()))
+            .filter(|dist| !dist.dist.is_editable() && !dist.dist.is_local())
+            .collect::<Vec<_>>();
+        nodes.sort_unstable_by_key(|dist| dist.to_comparator());
+
+        for node in nodes {
+            writeln!(
+                f,
+                "{}",
+                node.to_requirements_txt(&self.resolution.requires_python, self.include_markers)
+            )?;
         }
 
         Ok(())
     }
 }
 
+/// A [`std::fmt::Display`] implementation for the resolution graph, as a dependency tree.
+///
+/// Each package that was requested directly is written as `{name}=={version}`, followed by its
+/// dependencies, recursively, in the style of `pipdeptree`:
+///
+/// ```text
+/// flask==3.0.0
+///   - click [required: >=8.1.3, installed: 8.1.7]
+///   - jinja2 [required: >=3.1.2, installed: 3.1.3]
+///     - markupsafe [required: >=2.0, installed: 2.1.5]
+/// ```
+///
+/// The output is informational, and is not a valid `requirements.txt` file.
+#[derive(Debug)]
+pub struct DisplayResolutionTree<'a> {
+    /// The underlying graph.
+    resolution: &'a ResolverOutput,
+    /// The resolver marker environment, used to determine the packages that were requested
+    /// directly.
+    env: &'a ResolverEnvironment,
+}
+
+impl<'a> DisplayResolutionTree<'a> {
+    /// Create a new [`DisplayResolutionTree`] for the given graph.
+    pub fn new(underlying: &'a ResolverOutput, env: &'a ResolverEnvironment) -> Self {
+        Self {
+            resolution: underlying,
+            env,
+        }
+    }
+
+    /// Write the dependencies of the given node, skipping any that would form a cycle.
+    fn write_dependencies(
+        f: &mut std::fmt::Formatter<'_>,
+        graph: &RequirementsTxtGraph<'_>,
+        index: NodeIndex,
+        path: &mut Vec<NodeIndex>,
+    ) -> std::fmt::Result {
+        let node = &graph[index];
+
+        let mut dependencies = graph
+            .neighbors_directed(index, Direction::Outgoing)
+            .filter(|dependency| !path.contains(dependency))
+            .collect::<Vec<_>>();
+        dependencies.sort_unstable_by_key(|dependency| (graph[*dependency].name(), *dependency));
+
+        path.push(index);
+        for dependency in dependencies {
+            let dist = &graph[dependency];
+
+            // Determine the specifiers with which the parent requested the dependency.
+            let required = node
+                .requires_dist
+                .iter()
+                .filter(|requirement| requirement.name == *dist.name())
+                .filter_map(|requirement| match &requirement.source {
+                    RequirementSource::Registry { specifier, .. } if !specifier.is_empty() => {
+                        Some(specifier.to_string())
+                    }
+                    _ => None,
+                })
+                .unique()
+                .join(", ");
+            let required = if required.is_empty() {
+                "Any"
+            } else {
+                &required
+            };
+
+            writeln!(
+                f,
+                "{:indent$}- {} [required: {required}, installed: {}]",
+                "",
+                dist.name(),
+                dist.version,
+                indent = path.len() * 2,
+            )?;
+            Self::write_dependencies(f, graph, dependency, path)?;
+        }
+        path.pop();
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for DisplayResolutionTree<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let graph = strip_extras(&to_intermediate_graph(self.resolution));
+
+        // Start from the packages that were requested directly, along with any packages that
+        // aren't depended on by another package (e.g., those added by an override).
+        let direct = self
+            .resolution
+            .requirements
+            .iter()
+            .filter(|requirement| requirement.evaluate_markers(self.env.marker_environment(), &[]))
+            .map(|requirement| &requirement.name)
+            .collect::<FxHashSet<_>>();
+        let mut roots = graph
+            .node_indices()
+            .filter(|index| {
+                direct.contains(graph[*index].name())
+                    || graph
+                        .neighbors_directed(*index, Direction::Incoming)
+                        .next()
+                        .is_none()
+            })
+            .collect::<Vec<_>>();
+        roots.sort_unstable_by_key(|index| (graph[*index].to_comparator(), *index));
+
+        let mut path = Vec::new();
+        for index in roots {
+            let node = &graph[index];
+            writeln!(f, "{}=={}", node.name(), node.version)?;
+            Self::write_dependencies(f, &graph, index, &mut path)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Convert a [`petgraph::graph::Graph`] based on [`ResolutionGraphNode`] to a graph based on
+/// [`DisplayResolutionGraphNode`]. In other words: converts from [`AnnotatedDist`] to
+/// [`RequirementsTxtDist`].
+///
+/// We assign each package its propagated markers: In `requirements.txt`, we want a flat list
+/// that for each package tells us if it should be installed on the current platform, without
+/// looking at which packages depend on it.
+fn to_intermediate_graph(resolution: &ResolverOutput) -> IntermediatePetGraph<'_> {
+    resolution.graph.map(
+        |_index, node| match node {
+            ResolutionGraphNode::Root => DisplayResolutionGraphNode::Root,
+            ResolutionGraphNode::Dist(dist) => {
+                let dist = RequirementsTxtDist::from_annotated_dist(dist);
+                DisplayResolutionGraphNode::Dist(dist)
+            }
+        },
+        // We can drop the edge markers, while retaining their existence and direction for the
+        // annotations.
+        |_index, _edge| (),
+    )
+}
+
 /// Indicate the style of annotation comments, used to indicate the dependencies that requested each
 /// package.
 #[derive(Debug, Default, Copy, Clone, PartialEq, serde::Deserialize)]
@@ -613,6 +613,21 @@ impl ResolverOutput {
         self.dists().any(|dist| dist.name() == name)
     }
 
+    /// Returns an iterator over the name and version of each package that was resolved from a
+    /// registry.
+    pub fn registry_packages(&self) -> impl Iterator<Item = (&PackageName, &Version)> {
+        self.dists()
+            .filter(|dist| dist.is_base() && dist.index().is_some())
+            .map(|dist| (&dist.name, &dist.version))
+    }
+
+    /// Returns an iterator over the name, version, and distribution of each package.
+    pub fn packages(&self) -> impl Iterator<Item = (&PackageName, &Version, &ResolvedDist)> {
+        self.dists()
+            .filter(|dist| dist.is_base())
+            .map(|dist| (&dist.name, &dist.version, &dist.dist))
+    }
+
     /// Return the [`ResolutionDiagnostic`]s that were encountered while building the graph.
     pub fn diagnostics(&self) -> &[ResolutionDiagnostic] {
         &self.diagnostics
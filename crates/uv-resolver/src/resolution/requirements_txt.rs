@@ -5,8 +5,8 @@ use std::path::Path;
 use itertools::Itertools;
 
 use uv_distribution_types::{
-    DistributionMetadata, Name, RequiresPython, ResolvedDist, SimplifiedMarkerTree, Verbatim,
-    VersionOrUrlRef,
+    DistributionMetadata, Name, Requirement, RequiresPython, ResolvedDist, SimplifiedMarkerTree,
+    Verbatim, VersionOrUrlRef,
 };
 use uv_normalize::{ExtraName, PackageName};
 use uv_pep440::Version;
@@ -23,6 +23,8 @@ pub(crate) struct RequirementsTxtDist<'dist> {
     pub(crate) hashes: &'dist [HashDigest],
     pub(crate) markers: MarkerTree,
     pub(crate) extras: Vec<ExtraName>,
+    /// The dependencies declared by the distribution, if known.
+    pub(crate) requires_dist: &'dist [Requirement],
 }
 
 impl<'dist> RequirementsTxtDist<'dist> {
@@ -187,6 +189,11 @@ impl<'dist> RequirementsTxtDist<'dist> {
             } else {
                 vec![]
             },
+            requires_dist: annotated
+                .metadata
+                .as_ref()
+                .map(|metadata| &*metadata.requires_dist)
+                .unwrap_or_default(),
         }
     }
 }
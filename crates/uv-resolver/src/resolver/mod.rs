@@ -186,6 +186,7 @@ impl<'a, Context: BuildContext, InstalledPackages: InstalledPackagesProvider>
             build_context.locations(),
             build_context.build_options(),
             build_context.capabilities(),
+            options.wheel_tags.clone(),
         );
 
         Self::new_custom_io(
@@ -13,10 +13,10 @@ use uv_platform_tags::Tags;
 use uv_static::EnvVars;
 use uv_types::{BuildContext, HashStrategy};
 
-use crate::ExcludeNewer;
 use crate::flat_index::FlatIndex;
 use crate::version_map::VersionMap;
 use crate::yanks::AllowedYanks;
+use crate::{ExcludeNewer, WheelTags};
 
 pub type PackageVersionsResult = Result<VersionsResponse, uv_client::Error>;
 pub type WheelMetadataResult = Result<MetadataResponse, uv_distribution::Error>;
@@ -122,6 +122,7 @@ pub struct DefaultResolverProvider<'a, Context: BuildContext> {
     index_locations: &'a IndexLocations,
     build_options: &'a BuildOptions,
     capabilities: &'a IndexCapabilities,
+    wheel_tags: WheelTags,
 }
 
 impl<'a, Context: BuildContext> DefaultResolverProvider<'a, Context> {
@@ -137,6 +138,7 @@ impl<'a, Context: BuildContext> DefaultResolverProvider<'a, Context> {
         index_locations: &'a IndexLocations,
         build_options: &'a BuildOptions,
         capabilities: &'a IndexCapabilities,
+        wheel_tags: WheelTags,
     ) -> Self {
         Self {
             fetcher,
@@ -152,6 +154,7 @@ impl<'a, Context: BuildContext> DefaultResolverProvider<'a, Context> {
             index_locations,
             build_options,
             capabilities,
+            wheel_tags,
         }
     }
 
@@ -217,12 +220,14 @@ impl<Context: BuildContext> ResolverProvider for DefaultResolverProvider<'_, Con
                                     .and_then(|flat_index| flat_index.get(package_name))
                                     .cloned(),
                                 self.build_options,
+                                &self.wheel_tags,
                             ),
                             MetadataFormat::Flat(metadata) => VersionMap::from_flat_metadata(
                                 metadata,
                                 self.tags.as_ref(),
                                 &self.hasher,
                                 self.build_options,
+                                &self.wheel_tags,
                             ),
                         }
                     })
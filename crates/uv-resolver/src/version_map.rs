@@ -18,12 +18,13 @@ use uv_distribution_types::{
 };
 use uv_normalize::PackageName;
 use uv_pep440::Version;
-use uv_platform_tags::{IncompatibleTag, TagCompatibility, Tags};
+use uv_platform_tags::{IncompatibleTag, TagCompatibility, TagPriority, Tags};
 use uv_pypi_types::{HashDigest, ResolutionMetadata, Yanked};
 use uv_types::HashStrategy;
 use uv_warnings::warn_user_once;
 
 use crate::flat_index::FlatDistributions;
+use crate::wheel_tags::{WheelTag, WheelTags};
 use crate::yanks::AllowedYanks;
 
 /// A map from versions to distributions.
@@ -56,6 +57,7 @@ impl VersionMap {
         available_version_cutoff: Option<Timestamp>,
         flat_index: Option<FlatDistributions>,
         build_options: &BuildOptions,
+        wheel_tags: &WheelTags,
     ) -> Self {
         let mut stable = false;
         let mut local = false;
@@ -124,6 +126,7 @@ impl VersionMap {
                 simple_metadata,
                 no_binary: build_options.no_binary_package(package_name),
                 no_build: build_options.no_build_package(package_name),
+                wheel_tag: wheel_tags.get(package_name).cloned(),
                 index: index.clone(),
                 tags: tags.cloned(),
                 allowed_yanks: allowed_yanks.clone(),
@@ -141,13 +144,14 @@ impl VersionMap {
         tags: Option<&Tags>,
         hasher: &HashStrategy,
         build_options: &BuildOptions,
+        wheel_tags: &WheelTags,
     ) -> Self {
         let mut stable = false;
         let mut local = false;
         let mut map = BTreeMap::new();
 
         for (version, prioritized_dist) in
-            FlatDistributions::from_entries(flat_metadata, tags, hasher, build_options)
+            FlatDistributions::from_entries(flat_metadata, tags, hasher, build_options, wheel_tags)
         {
             stable |= version.is_stable();
             local |= version.is_local();
@@ -395,6 +399,8 @@ struct VersionMapLazy {
     no_binary: bool,
     /// When true, source dists aren't allowed.
     no_build: bool,
+    /// The wheel tag to select over all other compatible wheels, if any.
+    wheel_tag: Option<WheelTag>,
     /// The URL of the index where this package came from.
     index: IndexUrl,
     /// The set of compatibility tags that determines whether a wheel is usable
@@ -630,6 +636,14 @@ impl VersionMapLazy {
                 TagCompatibility::Incompatible(tag) => {
                     return WheelCompatibility::Incompatible(IncompatibleWheel::Tag(tag));
                 }
+                TagCompatibility::Compatible(_)
+                    if self
+                        .wheel_tag
+                        .as_ref()
+                        .is_some_and(|wheel_tag| wheel_tag.matches(filename)) =>
+                {
+                    Some(TagPriority::MAX)
+                }
                 TagCompatibility::Compatible(priority) => Some(priority),
             }
         } else {
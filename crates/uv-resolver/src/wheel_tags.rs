@@ -0,0 +1,161 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use rustc_hash::FxHashMap;
+
+use uv_distribution_filename::WheelFilename;
+use uv_normalize::PackageName;
+use uv_platform_tags::{AbiTag, LanguageTag, PlatformTag};
+
+/// A wheel tag to select for a package when multiple of its wheels are compatible, in the format
+/// `PACKAGE:PYTHON-ABI-PLATFORM` (e.g., `numpy:cp311-cp311-manylinux2014_x86_64`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WheelTagEntry {
+    pub package: PackageName,
+    pub tag: WheelTag,
+}
+
+impl FromStr for WheelTagEntry {
+    type Err = String;
+
+    /// Parses a [`WheelTagEntry`] from a string in the format `PACKAGE:PYTHON-ABI-PLATFORM`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((package, tag)) = s.split_once(':') else {
+            return Err(format!(
+                "Invalid `wheel-tag` value `{s}`: expected format `PACKAGE:PYTHON-ABI-PLATFORM`"
+            ));
+        };
+
+        let package = PackageName::from_str(package)
+            .map_err(|err| format!("Invalid `wheel-tag` package name `{package}`: {err}"))?;
+        let tag = WheelTag::from_str(tag)
+            .map_err(|err| format!("Invalid `wheel-tag` tag `{tag}`: {err}"))?;
+
+        Ok(Self { package, tag })
+    }
+}
+
+impl Display for WheelTagEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.package, self.tag)
+    }
+}
+
+/// A single Python, ABI, and platform tag triple, e.g., `cp311-cp311-manylinux2014_x86_64`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WheelTag {
+    python: LanguageTag,
+    abi: AbiTag,
+    platform: PlatformTag,
+}
+
+impl WheelTag {
+    /// Returns `true` if the wheel was built for this tag.
+    pub fn matches(&self, filename: &WheelFilename) -> bool {
+        filename.python_tags().contains(&self.python)
+            && filename.abi_tags().contains(&self.abi)
+            && filename.platform_tags().contains(&self.platform)
+    }
+}
+
+impl FromStr for WheelTag {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(3, '-');
+        let (Some(python_tag), Some(abi_tag), Some(platform_tag)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err("expected format `PYTHON-ABI-PLATFORM`".to_string());
+        };
+        Ok(Self {
+            python: LanguageTag::from_str(python_tag).map_err(|err| err.to_string())?,
+            abi: AbiTag::from_str(abi_tag).map_err(|err| err.to_string())?,
+            platform: PlatformTag::from_str(platform_tag).map_err(|err| err.to_string())?,
+        })
+    }
+}
+
+impl Display for WheelTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}-{}", self.python, self.abi, self.platform)
+    }
+}
+
+/// The wheel tags to select for specific packages, overriding the default preference among
+/// compatible wheels.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct WheelTags(FxHashMap<PackageName, WheelTag>);
+
+impl WheelTags {
+    /// Returns the tag to select for the given package, if any.
+    pub fn get(&self, package_name: &PackageName) -> Option<&WheelTag> {
+        self.0.get(package_name)
+    }
+
+    /// Returns `true` if the wheel should be selected over all other compatible wheels of its
+    /// package.
+    pub fn prefers(&self, filename: &WheelFilename) -> bool {
+        self.get(&filename.name)
+            .is_some_and(|tag| tag.matches(filename))
+    }
+
+    /// Returns `true` if no tags are set.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromIterator<WheelTagEntry> for WheelTags {
+    fn from_iter<T: IntoIterator<Item = WheelTagEntry>>(iter: T) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|entry| (entry.package, entry.tag))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_distribution_filename::WheelFilename;
+
+    use super::{WheelTagEntry, WheelTags};
+
+    #[test]
+    fn parse() {
+        let entry = WheelTagEntry::from_str("numpy:cp311-cp311-manylinux2014_x86_64").unwrap();
+        assert_eq!(entry.package.as_ref(), "numpy");
+        assert_eq!(entry.to_string(), "numpy:cp311-cp311-manylinux2014_x86_64");
+
+        assert!(WheelTagEntry::from_str("numpy").is_err());
+        assert!(WheelTagEntry::from_str("numpy:cp311-cp311").is_err());
+        assert!(WheelTagEntry::from_str("numpy:cp311-cp311-").is_err());
+    }
+
+    #[test]
+    fn prefers() {
+        let wheel_tags = [
+            WheelTagEntry::from_str("numpy:cp311-cp311-manylinux2014_x86_64").unwrap(),
+            WheelTagEntry::from_str("tqdm:py3-none-any").unwrap(),
+        ]
+        .into_iter()
+        .collect::<WheelTags>();
+
+        let manylinux2014 = WheelFilename::from_str(
+            "numpy-1.26.0-cp311-cp311-manylinux_2_17_x86_64.manylinux2014_x86_64.whl",
+        )
+        .unwrap();
+        let manylinux1 =
+            WheelFilename::from_str("numpy-1.26.0-cp311-cp311-manylinux1_x86_64.whl").unwrap();
+        let compressed = WheelFilename::from_str("tqdm-4.66.1-py2.py3-none-any.whl").unwrap();
+        let other = WheelFilename::from_str("anyio-4.0.0-py3-none-any.whl").unwrap();
+
+        assert!(wheel_tags.prefers(&manylinux2014));
+        assert!(!wheel_tags.prefers(&manylinux1));
+        assert!(wheel_tags.prefers(&compressed));
+        assert!(!wheel_tags.prefers(&other));
+    }
+}
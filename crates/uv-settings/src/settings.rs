@@ -1606,6 +1606,19 @@ pub struct PipOptions {
         "#
     )]
     pub output_file: Option<PathBuf>,
+    /// Write the resolved versions generated by `uv pip compile` to the given constraints file,
+    /// alongside the main output.
+    ///
+    /// Each resolved package is pinned to its selected version, without extras, hashes, or
+    /// annotations. Editable and local packages are omitted.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            constraint-output = "constraints.txt"
+        "#
+    )]
+    pub constraint_output: Option<PathBuf>,
     /// Include extras in the output file.
     ///
     /// By default, uv strips extras, as any packages pulled in by the extras are already included
@@ -1661,6 +1674,20 @@ pub struct PipOptions {
         "#
     )]
     pub custom_compile_command: Option<String>,
+    /// A comment block to include at the top of the output file generated by `uv pip compile`,
+    /// before the header comment.
+    ///
+    /// Each line is prefixed with `# `, unless it's already a comment. Provide `@<path>` to read
+    /// the comment block from a file. The comment block is included even when `no-header` is
+    /// enabled.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            header-comment = "Do not edit: generated by `make requirements`."
+        "#
+    )]
+    pub header_comment: Option<String>,
     /// Include distribution hashes in the output file.
     #[option(
         default = "false",
@@ -1821,6 +1848,100 @@ pub struct PipOptions {
         "#
     )]
     pub emit_index_annotation: Option<bool>,
+    /// Include only the direct dependencies in the output, omitting any transitive dependencies.
+    ///
+    /// Each direct dependency is pinned to the version selected by the resolver. The output is
+    /// _not_ a complete locked environment: installing it may select different versions of the
+    /// transitive dependencies, or fail if they have incompatible requirements.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            emit-direct-only = true
+        "#
+    )]
+    pub emit_direct_only: Option<bool>,
+    /// Include comment annotations with the download URL of each package pinned from a registry
+    /// (e.g., `# url: https://files.pythonhosted.org/...`).
+    ///
+    /// The URL is that of the distribution selected for the target environment: a wheel, if a
+    /// compatible one is available, and the source distribution otherwise.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            emit-package-urls = true
+        "#
+    )]
+    pub emit_package_urls: Option<bool>,
+    /// Include comment annotations with the SPDX license expression of each package (e.g.,
+    /// `# license: MIT`).
+    ///
+    /// The license is read from the `METADATA` file of each package's wheel, which is downloaded
+    /// (or built) if necessary: the `License-Expression` field, the `License` field if it's a
+    /// valid SPDX expression, or an unambiguous license classifier.
+    /// Packages whose license can't be determined are annotated with `# license: UNKNOWN`.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            emit-license = true
+        "#
+    )]
+    pub emit_license: Option<bool>,
+    /// Include comment annotations with the extras through which each package was resolved
+    /// (e.g., `# extras: security` for `requests`, when `requests[security]` is required).
+    ///
+    /// Packages that were only resolved without extras are not annotated.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            emit-extras = true
+        "#
+    )]
+    pub emit_extras: Option<bool>,
+    /// Write the dependency tree generated by `uv pip compile` to a file alongside the output
+    /// file, with the `.tree.txt` extension (e.g., `requirements.tree.txt` for
+    /// `requirements.txt`).
+    ///
+    /// Each package that was requested directly is listed with its transitive dependencies nested
+    /// beneath it, in the style of `pipdeptree`. The tree is informational and is not a valid
+    /// requirements file.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            emit-tree = true
+        "#
+    )]
+    pub emit_tree: Option<bool>,
+    /// Group the output into sections by the extras that request each package.
+    ///
+    /// Packages that are required without an extra are listed first. Packages that are only
+    /// required via extras (e.g., with `--extra security`) follow in sections with a header
+    /// comment naming those extras (e.g., `# [extra: security]`).
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            group-by-extra = true
+        "#
+    )]
+    pub group_by_extra: Option<bool>,
+    /// Treat extra-related resolution warnings as errors.
+    ///
+    /// By default, requesting an extra that a package doesn't provide (e.g., `black[colorama]`
+    /// for a version of `black` without a `colorama` extra) emits a warning. When enabled,
+    /// `uv pip compile` fails instead, and no output is written.
+    #[option(
+        default = "false",
+        value_type = "bool",
+        example = r#"
+            strict-extras = true
+        "#
+    )]
+    pub strict_extras: Option<bool>,
     /// The style of the annotation comments included in the output file, used to indicate the
     /// source of each package.
     #[option(
@@ -546,6 +546,17 @@ impl EnvVars {
         format!("UV_INDEX_{name}_PASSWORD")
     }
 
+    /// Constrains the version of a package when `uv pip install --constraints-from-env` is set.
+    ///
+    /// The `name` parameter is the normalized package name, uppercased, with `-` replaced by
+    /// `_`. For example, `UV_CONSTRAINT_TYPING_EXTENSIONS=4.12.2` constrains `typing-extensions`
+    /// to `==4.12.2`, and `UV_CONSTRAINT_NUMPY=">=1.26,<2"` constrains `numpy` to `>=1.26,<2`.
+    #[attr_added_in("next release")]
+    #[attr_env_var_pattern("UV_CONSTRAINT_{name}")]
+    pub fn constraint(name: &str) -> String {
+        format!("UV_CONSTRAINT_{name}")
+    }
+
     /// Used to set the uv commit hash at build time via `build.rs`.
     #[attr_hidden]
     #[attr_added_in("0.1.11")]
@@ -633,6 +644,16 @@ impl EnvVars {
     #[attr_added_in("0.9.29")]
     pub const UV_INTERNAL__PYTHONHOME: &'static str = "UV_INTERNAL__PYTHONHOME";
 
+    /// Set by uv for `--hooks-pre` and `--hooks-post` scripts to the space-separated names of
+    /// the packages being installed or reinstalled.
+    #[attr_added_in("next release")]
+    pub const UV_HOOK_PACKAGES: &'static str = "UV_HOOK_PACKAGES";
+
+    /// Set by uv for `--hooks-pre` and `--hooks-post` scripts to the root of the environment
+    /// being modified.
+    #[attr_added_in("next release")]
+    pub const UV_HOOK_PREFIX: &'static str = "UV_HOOK_PREFIX";
+
     /// Path to system-level configuration directory on Unix systems.
     #[attr_added_in("0.4.26")]
     pub const XDG_CONFIG_DIRS: &'static str = "XDG_CONFIG_DIRS";
@@ -984,6 +1005,26 @@ impl EnvVars {
     #[attr_added_in("0.7.13")]
     pub const PYTHONHOME: &'static str = "PYTHONHOME";
 
+    /// Names a Python file to run at the start of an interactive session.
+    #[attr_hidden]
+    #[attr_added_in("next release")]
+    pub const PYTHONSTARTUP: &'static str = "PYTHONSTARTUP";
+
+    /// Overrides the base directory for user site-packages.
+    #[attr_hidden]
+    #[attr_added_in("next release")]
+    pub const PYTHONUSERBASE: &'static str = "PYTHONUSERBASE";
+
+    /// Overrides the name of the platform-specific library directory, e.g., `lib64`.
+    #[attr_hidden]
+    #[attr_added_in("next release")]
+    pub const PYTHONPLATLIBDIR: &'static str = "PYTHONPLATLIBDIR";
+
+    /// Overrides `sys.argv[0]` for framework builds of Python on macOS.
+    #[attr_hidden]
+    #[attr_added_in("next release")]
+    pub const PYTHONEXECUTABLE: &'static str = "PYTHONEXECUTABLE";
+
     /// Used to correctly detect virtual environments when using trampolines.
     #[attr_hidden]
     #[attr_added_in("0.7.13")]
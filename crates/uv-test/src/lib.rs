@@ -1641,6 +1641,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache path` command.
+    pub fn cache_path(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("path");
+        self.add_shared_options(&mut command, false);
+        command
+    }
+
     /// Create a `uv cache size` command.
     pub fn cache_size(&self) -> Command {
         let mut command = self.new_command();
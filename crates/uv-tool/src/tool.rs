@@ -34,6 +34,9 @@ pub struct Tool {
     entrypoints: Vec<ToolEntrypoint>,
     /// The [`ToolOptions`] used to install this tool.
     options: ToolOptions,
+    /// Whether the launchers of GUI entry points exit once the application starts, i.e.,
+    /// `--gui-detach`.
+    gui_detach: bool,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -53,6 +56,8 @@ struct ToolWire {
     entrypoints: Vec<ToolEntrypoint>,
     #[serde(default)]
     options: ToolOptionsWire,
+    #[serde(default)]
+    gui_detach: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
@@ -80,6 +85,7 @@ impl From<Tool> for ToolWire {
             python: tool.python,
             entrypoints: tool.entrypoints,
             options: tool.options.into(),
+            gui_detach: tool.gui_detach,
         }
     }
 }
@@ -104,6 +110,7 @@ impl TryFrom<ToolWire> for Tool {
             python: tool.python,
             entrypoints: tool.entrypoints,
             options: tool.options.into(),
+            gui_detach: tool.gui_detach,
         })
     }
 }
@@ -180,6 +187,7 @@ impl Tool {
         python: Option<PythonRequest>,
         entrypoints: impl IntoIterator<Item = ToolEntrypoint>,
         options: ToolOptions,
+        gui_detach: bool,
     ) -> Self {
         let mut entrypoints: Vec<_> = entrypoints.into_iter().collect();
         entrypoints.sort();
@@ -192,6 +200,7 @@ impl Tool {
             python,
             entrypoints,
             options,
+            gui_detach,
         }
     }
 
@@ -335,6 +344,10 @@ impl Tool {
             value(entrypoints)
         });
 
+        if self.gui_detach {
+            table.insert("gui-detach", value(true));
+        }
+
         if self.options != ToolOptions::default() {
             let serialized = serde::Serialize::serialize(
                 &ToolOptionsWire::from(self.options.clone()),
@@ -387,6 +400,10 @@ impl Tool {
     pub fn options(&self) -> &ToolOptions {
         &self.options
     }
+
+    pub fn gui_detach(&self) -> bool {
+        self.gui_detach
+    }
 }
 
 impl ToolEntrypoint {
@@ -14,17 +14,20 @@ const LAUNCHER_I686_GUI: &[u8] = include_bytes!("../trampolines/uv-trampoline-i6
 const LAUNCHER_I686_CONSOLE: &[u8] =
     include_bytes!("../trampolines/uv-trampoline-i686-console.exe");
 
-#[cfg(all(windows, target_arch = "x86_64"))]
+// 64-bit builds ship both 64-bit launchers, such that a launcher matches the architecture of the
+// interpreter it launches, e.g., an x86_64 Python installed by an ARM64 uv, rather than running
+// under emulation.
+#[cfg(all(windows, any(target_arch = "x86_64", target_arch = "aarch64")))]
 const LAUNCHER_X86_64_GUI: &[u8] = include_bytes!("../trampolines/uv-trampoline-x86_64-gui.exe");
 
-#[cfg(all(windows, target_arch = "x86_64"))]
+#[cfg(all(windows, any(target_arch = "x86_64", target_arch = "aarch64")))]
 const LAUNCHER_X86_64_CONSOLE: &[u8] =
     include_bytes!("../trampolines/uv-trampoline-x86_64-console.exe");
 
-#[cfg(all(windows, target_arch = "aarch64"))]
+#[cfg(all(windows, any(target_arch = "x86_64", target_arch = "aarch64")))]
 const LAUNCHER_AARCH64_GUI: &[u8] = include_bytes!("../trampolines/uv-trampoline-aarch64-gui.exe");
 
-#[cfg(all(windows, target_arch = "aarch64"))]
+#[cfg(all(windows, any(target_arch = "x86_64", target_arch = "aarch64")))]
 const LAUNCHER_AARCH64_CONSOLE: &[u8] =
     include_bytes!("../trampolines/uv-trampoline-aarch64-console.exe");
 
@@ -42,12 +45,28 @@ const RESOURCE_PYTHON_PATH: windows::core::PCWSTR = windows::core::w!("UV_PYTHON
 // sits nicely under the PE format.
 #[cfg(windows)]
 const RESOURCE_SCRIPT_DATA: windows::core::PCWSTR = windows::core::w!("UV_SCRIPT_DATA");
+#[cfg(windows)]
+const RESOURCE_DETACH: windows::core::PCWSTR = windows::core::w!("UV_DETACH");
+// Omitted by launchers written before the metadata format was versioned.
+#[cfg(windows)]
+const RESOURCE_TRAMPOLINE_VERSION: windows::core::PCWSTR =
+    windows::core::w!("UV_TRAMPOLINE_VERSION");
+#[cfg(windows)]
+const LATEST_FORMAT: [u8; 1] = [1];
 
 #[derive(Debug)]
 pub struct Launcher {
     pub kind: LauncherKind,
     pub python_path: PathBuf,
     pub script_data: Option<Vec<u8>>,
+    /// Whether a GUI launcher exits as soon as the child is running, rather than waiting for it.
+    ///
+    /// Saves a launcher process for the lifetime of a GUI application. The child isn't assigned
+    /// to a job object, and the launcher always exits with 0. Only GUI launchers can be
+    /// detached, since console launchers must forward the child's exit code.
+    pub detach: bool,
+    /// The version of the metadata format.
+    pub format: LauncherFormat,
 }
 
 impl Launcher {
@@ -97,19 +116,28 @@ impl Launcher {
                 return Err(Error::UnprocessableMetadata);
             };
 
-            let Some(path_data) = read_resource(module, RESOURCE_PYTHON_PATH) else {
+            // Check the format version first, so that launchers from newer versions of uv are
+            // reported as such, rather than as malformed.
+            let format = read_resource(module, RESOURCE_TRAMPOLINE_VERSION)
+                .map(|data| LauncherFormat::from_resource_value(&data))
+                .transpose()?
+                .unwrap_or(LauncherFormat::Legacy);
+
+            let Some(python_path) = read_python_path(module)? else {
                 return Ok(None);
             };
-            let python_path = PathBuf::from(
-                String::from_utf8(path_data).map_err(|err| Error::InvalidPath(err.utf8_error()))?,
-            );
 
             let script_data = read_resource(module, RESOURCE_SCRIPT_DATA);
 
+            let detach = read_resource(module, RESOURCE_DETACH)
+                .is_some_and(|data| data.first().is_some_and(|flag| *flag != 0));
+
             Ok(Some(Self {
                 kind,
                 python_path,
                 script_data,
+                detach,
+                format,
             }))
         })();
 
@@ -125,20 +153,31 @@ impl Launcher {
 
     /// Write this trampoline launcher to a file.
     ///
+    /// See [`Launcher::write_to`].
+    pub fn write_to_file(self, file: &mut File, is_gui: bool) -> Result<(), Error> {
+        self.write_to(file, is_gui)
+    }
+
+    /// Write this trampoline launcher to the given writer.
+    ///
     /// On Unix, this always returns [`Error::NotWindows`]. Trampolines are a Windows-specific
     /// feature and cannot be written on other platforms.
     #[cfg(not(windows))]
-    pub fn write_to_file(self, _file: &mut File, _is_gui: bool) -> Result<(), Error> {
+    pub fn write_to(self, _writer: &mut impl io::Write, _is_gui: bool) -> Result<(), Error> {
         Err(Error::NotWindows)
     }
 
-    /// Write this trampoline launcher to a file.
+    /// Write this trampoline launcher to the given writer.
+    ///
+    /// The output is the base launcher binary with this launcher's metadata embedded as resources,
+    /// i.e., exactly what [`Launcher::try_from_path`] reads back.
     #[cfg(windows)]
-    pub fn write_to_file(self, file: &mut File, is_gui: bool) -> Result<(), Error> {
-        use std::io::Write;
-        use uv_fs::Simplified;
+    pub fn write_to(self, writer: &mut impl io::Write, is_gui: bool) -> Result<(), Error> {
+        if self.detach && !is_gui {
+            return Err(Error::DetachedConsoleLauncher);
+        }
 
-        let python_path = self.python_path.simplified_display().to_string();
+        let python_path = python_path_resource(&self.python_path)?;
 
         // Create temporary file for the base launcher
         let temp_dir = tempfile::TempDir::new()?;
@@ -147,47 +186,213 @@ impl Launcher {
             .join(format!("uv-trampoline-{}.exe", std::process::id()));
 
         // Write the launcher binary
-        fs_err::write(&temp_file, get_launcher_bin(is_gui)?)?;
+        fs_err::write(&temp_file, get_launcher_bin(is_gui, &self.python_path)?)?;
 
         // Write resources
-        let resources = &[
-            (
-                RESOURCE_TRAMPOLINE_KIND,
-                &[self.kind.to_resource_value()][..],
-            ),
-            (RESOURCE_PYTHON_PATH, python_path.as_bytes()),
+        let kind = [self.kind.to_resource_value()];
+        let format = self.format.to_resource_value();
+        let mut resources = vec![
+            (RESOURCE_TRAMPOLINE_KIND, &kind[..]),
+            (RESOURCE_PYTHON_PATH, &python_path[..]),
         ];
-        if let Some(script_data) = self.script_data {
-            let mut all_resources = resources.to_vec();
-            all_resources.push((RESOURCE_SCRIPT_DATA, &script_data));
-            write_resources(&temp_file, &all_resources)?;
-        } else {
-            write_resources(&temp_file, resources)?;
+        if let Some(script_data) = &self.script_data {
+            resources.push((RESOURCE_SCRIPT_DATA, script_data));
+        }
+        if self.detach {
+            resources.push((RESOURCE_DETACH, &[1][..]));
         }
+        if let Some(format) = &format {
+            resources.push((RESOURCE_TRAMPOLINE_VERSION, format));
+        }
+        write_resources(&temp_file, &resources)?;
 
         // Read back the complete file
         let launcher = fs_err::read(&temp_file)?;
         fs_err::remove_file(&temp_file)?;
 
         // Then write it to the handle
-        file.write_all(&launcher)?;
+        writer.write_all(&launcher)?;
 
         Ok(())
     }
 
+    /// Resolve the embedded Python path of the trampoline at `path` as the trampoline does at
+    /// launch, i.e., resolving a relative path against the launcher's directory.
+    pub fn resolve_python_path(&self, path: &Path) -> PathBuf {
+        resolve_relative_to(&self.python_path, path)
+    }
+
+    /// Read the trampoline at `path` and return the resolved path of the Python executable it
+    /// launches, if that executable doesn't exist, e.g., because the interpreter was uninstalled.
+    ///
+    /// Returns `None` if the executable exists, and for files that aren't trampolines or whose
+    /// metadata can't be read. On Unix, this always returns `None`.
+    pub fn dangling_python_path(path: &Path) -> Option<PathBuf> {
+        let launcher = Self::try_from_path(path).ok()??;
+        let python_path = launcher.resolve_python_path(path);
+        python_path
+            .symlink_metadata()
+            .is_err()
+            .then_some(python_path)
+    }
+
+    /// Point the trampoline at `path` to a different Python executable, in place.
+    ///
+    /// On Unix, this always returns [`Error::NotWindows`]. Trampolines are a Windows-specific
+    /// feature and cannot be written on other platforms.
+    #[cfg(not(windows))]
+    pub fn retarget(_path: &Path, _python_path: &Path) -> Result<(), Error> {
+        Err(Error::NotWindows)
+    }
+
+    /// Point the trampoline at `path` to a different Python executable, in place.
+    ///
+    /// Only the Python path resource is rewritten; the launcher binary, the embedded script, and
+    /// the remaining metadata are left as-is. This is much cheaper than writing a new launcher,
+    /// and updates the existing file rather than replacing it, e.g., when a managed Python is
+    /// upgraded.
+    ///
+    /// The launcher is rewritten in full instead if its binary isn't the one this version of uv
+    /// would write, e.g., because it was created by an older version of uv or the new
+    /// interpreter's architecture doesn't match, or if its metadata uses an older format.
+    ///
+    /// Returns [`Error::NotALauncher`] if the file is not a trampoline executable.
+    #[cfg(windows)]
+    pub fn retarget(path: &Path, python_path: &Path) -> Result<(), Error> {
+        let Some(launcher) = Self::try_from_path(path)? else {
+            return Err(Error::NotALauncher(path.to_path_buf()));
+        };
+
+        let image = fs_err::read(path)?;
+        let header =
+            goblin::pe::header::Header::parse(&image).map_err(|_| Error::UnprocessableMetadata)?;
+        let is_gui = header.optional_header.is_some_and(|header| {
+            header.windows_fields.subsystem == goblin::pe::subsystem::IMAGE_SUBSYSTEM_WINDOWS_GUI
+        });
+        let is_current = get_launcher_bin(is_gui, python_path)
+            .is_ok_and(|launcher_bin| has_same_code(&image, launcher_bin));
+        if launcher.format.is_stale() || !is_current {
+            let mut contents = Vec::new();
+            Self {
+                python_path: python_path.to_path_buf(),
+                format: LauncherFormat::LATEST,
+                ..launcher
+            }
+            .write_to(&mut contents, is_gui)?;
+            uv_fs::write_atomic_sync(path, contents)?;
+            return Ok(());
+        }
+
+        let data = python_path_resource(python_path)?;
+        write_resources(path, &[(RESOURCE_PYTHON_PATH, &data)])
+    }
+
+    /// Detach the GUI trampoline at `path`, in place. See [`Launcher::detach`].
+    ///
+    /// On Unix, this always returns [`Error::NotWindows`]. Trampolines are a Windows-specific
+    /// feature and cannot be written on other platforms.
+    #[cfg(not(windows))]
+    pub fn detach_gui(_path: &Path) -> Result<bool, Error> {
+        Err(Error::NotWindows)
+    }
+
+    /// Detach the GUI trampoline at `path`, in place. See [`Launcher::detach`].
+    ///
+    /// Returns `false`, leaving the file unchanged, if it's a console launcher, e.g., one of the
+    /// `console_scripts` of a package that also has `gui_scripts`.
+    ///
+    /// Returns [`Error::NotALauncher`] if the file is not a trampoline executable.
+    #[cfg(windows)]
+    pub fn detach_gui(path: &Path) -> Result<bool, Error> {
+        if Self::try_from_path(path)?.is_none() {
+            return Err(Error::NotALauncher(path.to_path_buf()));
+        }
+
+        let image = fs_err::read(path)?;
+        let header =
+            goblin::pe::header::Header::parse(&image).map_err(|_| Error::UnprocessableMetadata)?;
+        let is_gui = header.optional_header.is_some_and(|header| {
+            header.windows_fields.subsystem == goblin::pe::subsystem::IMAGE_SUBSYSTEM_WINDOWS_GUI
+        });
+        if !is_gui {
+            return Ok(false);
+        }
+
+        write_resources(path, &[(RESOURCE_DETACH, &[1])])?;
+        Ok(true)
+    }
+
     #[must_use]
     pub fn with_python_path(self, path: PathBuf) -> Self {
         Self {
-            kind: self.kind,
             python_path: path,
-            script_data: self.script_data,
+            ..self
+        }
+    }
+
+    /// Exit as soon as the child is running, rather than waiting for it. See
+    /// [`Launcher::detach`]; writing a detached console launcher fails.
+    #[must_use]
+    pub fn with_detach(self) -> Self {
+        Self {
+            detach: true,
+            ..self
+        }
+    }
+}
+
+/// The version of the launcher metadata format.
+///
+/// Newer launchers record the version in a dedicated resource, such that a trampoline (or a
+/// version of uv) that doesn't understand the format can report that, rather than misinterpreting
+/// the metadata.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LauncherFormat {
+    /// A launcher written before the metadata format was versioned.
+    Legacy,
+    /// The first versioned format.
+    #[default]
+    V1,
+}
+
+impl LauncherFormat {
+    /// The newest format, as written by this version of uv.
+    pub const LATEST: Self = Self::V1;
+
+    /// Read the metadata format of a trampoline executable file.
+    ///
+    /// Returns `Ok(None)` if the file is not a trampoline executable, and
+    /// [`Error::UnsupportedLauncherFormat`] if it was created by a newer version of uv.
+    pub fn from_path(path: &Path) -> Result<Option<Self>, Error> {
+        Ok(Launcher::try_from_path(path)?.map(|launcher| launcher.format))
+    }
+
+    /// Returns `true` if the format is older than [`LauncherFormat::LATEST`].
+    pub fn is_stale(self) -> bool {
+        self < Self::LATEST
+    }
+
+    #[cfg(windows)]
+    fn to_resource_value(self) -> Option<[u8; 1]> {
+        match self {
+            Self::Legacy => None,
+            Self::V1 => Some([1]),
+        }
+    }
+
+    #[cfg(windows)]
+    fn from_resource_value(value: &[u8]) -> Result<Self, Error> {
+        match value {
+            [1] => Ok(Self::V1),
+            [version] => Err(Error::UnsupportedLauncherFormat(*version)),
+            _ => Err(Error::UnprocessableMetadata),
         }
     }
 }
 
 /// The kind of trampoline launcher to create.
 ///
-/// See [`uv-trampoline::bounce::TrampolineKind`].
+/// See [`uv-trampoline::metadata::TrampolineKind`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LauncherKind {
     /// The trampoline should execute itself, it's a zipped Python script.
@@ -197,22 +402,78 @@ pub enum LauncherKind {
 }
 
 impl LauncherKind {
-    #[cfg(windows)]
-    fn to_resource_value(self) -> u8 {
+    /// The `UV_TRAMPOLINE_KIND` resource value of a [`LauncherKind::Script`] launcher.
+    pub const SCRIPT_RESOURCE_VALUE: u8 = 1;
+    /// The `UV_TRAMPOLINE_KIND` resource value of a [`LauncherKind::Python`] launcher.
+    pub const PYTHON_RESOURCE_VALUE: u8 = 2;
+
+    /// The value of the `UV_TRAMPOLINE_KIND` resource that identifies this kind of launcher.
+    pub fn to_resource_value(self) -> u8 {
         match self {
-            Self::Script => 1,
-            Self::Python => 2,
+            Self::Script => Self::SCRIPT_RESOURCE_VALUE,
+            Self::Python => Self::PYTHON_RESOURCE_VALUE,
         }
     }
 
-    #[cfg(windows)]
-    fn from_resource_value(value: u8) -> Option<Self> {
+    /// Parse the value of the `UV_TRAMPOLINE_KIND` resource.
+    pub fn from_resource_value(value: u8) -> Option<Self> {
         match value {
-            1 => Some(Self::Script),
-            2 => Some(Self::Python),
+            Self::SCRIPT_RESOURCE_VALUE => Some(Self::Script),
+            Self::PYTHON_RESOURCE_VALUE => Some(Self::Python),
             _ => None,
         }
     }
+
+    /// Determine the kind of a trampoline executable file, reading only its kind resource.
+    ///
+    /// Unlike [`Launcher::try_from_path`], this doesn't read or validate the rest of the metadata,
+    /// so it can be used to classify executables cheaply.
+    ///
+    /// On Unix, this always returns [`None`]. Trampolines are a Windows-specific feature and cannot
+    /// be read on other platforms.
+    #[cfg(not(windows))]
+    pub fn detect(_path: &Path) -> Option<Self> {
+        None
+    }
+
+    /// Determine the kind of a trampoline executable file, reading only its kind resource.
+    ///
+    /// Unlike [`Launcher::try_from_path`], this doesn't read or validate the rest of the metadata,
+    /// so it can be used to classify executables cheaply.
+    ///
+    /// Returns [`None`] if the file is not a trampoline executable.
+    #[cfg(windows)]
+    pub fn detect(path: &Path) -> Option<Self> {
+        use std::os::windows::ffi::OsStrExt;
+        use windows::Win32::System::LibraryLoader::LOAD_LIBRARY_AS_DATAFILE;
+        use windows::Win32::System::LibraryLoader::LoadLibraryExW;
+
+        let path_str = path
+            .as_os_str()
+            .encode_wide()
+            .chain(std::iter::once(0))
+            .collect::<Vec<_>>();
+
+        // SAFETY: winapi call; null-terminated strings
+        #[allow(unsafe_code)]
+        let module = unsafe {
+            LoadLibraryExW(
+                windows::core::PCWSTR(path_str.as_ptr()),
+                None,
+                LOAD_LIBRARY_AS_DATAFILE,
+            )
+            .ok()
+        }?;
+
+        let kind = read_resource(module, RESOURCE_TRAMPOLINE_KIND)
+            .and_then(|data| Self::from_resource_value(*data.first()?));
+
+        // SAFETY: winapi call; handle is known to be valid.
+        #[allow(unsafe_code)]
+        let _ = unsafe { windows::Win32::Foundation::FreeLibrary(module) };
+
+        kind
+    }
 }
 
 /// Note: The caller is responsible for adding the path of the wheel we're installing.
@@ -232,6 +493,21 @@ pub enum Error {
     UnprocessableMetadata,
     #[error("Resources over 2^32 bytes are not supported")]
     ResourceTooLarge,
+    #[error(
+        "Launcher metadata format version {0} is not supported; the launcher was created by a newer version of uv"
+    )]
+    UnsupportedLauncherFormat(u8),
+    #[error(
+        "Only GUI launchers can be detached, since console launchers forward the exit code of their child"
+    )]
+    DetachedConsoleLauncher,
+    #[error(
+        "Unable to create Windows launcher for a Python executable path that is not valid Unicode: {}",
+        .0.user_display()
+    )]
+    NonUnicodePythonPath(PathBuf),
+    #[error("Not a uv trampoline: {}", .0.user_display())]
+    NotALauncher(PathBuf),
     #[error("Failed to update Windows PE resources: {}", path.user_display())]
     WriteResources {
         path: PathBuf,
@@ -240,39 +516,105 @@ pub enum Error {
     },
 }
 
-#[allow(clippy::unnecessary_wraps, unused_variables)]
+/// The architecture of a launcher binary.
 #[cfg(windows)]
-fn get_launcher_bin(gui: bool) -> Result<&'static [u8], Error> {
-    Ok(match std::env::consts::ARCH {
-        #[cfg(all(windows, target_arch = "x86"))]
-        "x86" => {
-            if gui {
-                LAUNCHER_I686_GUI
-            } else {
-                LAUNCHER_I686_CONSOLE
-            }
-        }
-        #[cfg(all(windows, target_arch = "x86_64"))]
-        "x86_64" => {
-            if gui {
-                LAUNCHER_X86_64_GUI
-            } else {
-                LAUNCHER_X86_64_CONSOLE
-            }
-        }
-        #[cfg(all(windows, target_arch = "aarch64"))]
-        "aarch64" => {
-            if gui {
-                LAUNCHER_AARCH64_GUI
-            } else {
-                LAUNCHER_AARCH64_CONSOLE
-            }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LauncherArch {
+    X86,
+    X86_64,
+    Aarch64,
+}
+
+#[cfg(windows)]
+impl LauncherArch {
+    /// The architecture uv was built for.
+    fn host() -> Result<Self, Error> {
+        match std::env::consts::ARCH {
+            "x86" => Ok(Self::X86),
+            "x86_64" => Ok(Self::X86_64),
+            "aarch64" => Ok(Self::Aarch64),
+            arch => Err(Error::UnsupportedWindowsArch(arch)),
         }
-        #[cfg(windows)]
-        arch => {
-            return Err(Error::UnsupportedWindowsArch(arch));
+    }
+
+    /// Read the architecture of an executable, e.g., a `python.exe`, from its PE header.
+    ///
+    /// Returns `None` if the executable can't be read, e.g., because its path is relative to the
+    /// launcher, or if it's not a PE image of a supported architecture.
+    fn from_executable(path: &Path) -> Option<Self> {
+        let image = fs_err::read(path).ok()?;
+        let header = goblin::pe::header::Header::parse(&image).ok()?;
+        Self::from_machine(header.coff_header.machine)
+    }
+
+    /// Map the machine type from a COFF header to an architecture.
+    fn from_machine(machine: u16) -> Option<Self> {
+        match machine {
+            goblin::pe::header::COFF_MACHINE_X86 => Some(Self::X86),
+            goblin::pe::header::COFF_MACHINE_X86_64 => Some(Self::X86_64),
+            goblin::pe::header::COFF_MACHINE_ARM64 => Some(Self::Aarch64),
+            _ => None,
         }
-    })
+    }
+}
+
+/// Return the launcher binary for the interpreter at `python_executable`.
+///
+/// The launcher matches the architecture of the interpreter, if a launcher for that architecture
+/// is embedded in this build of uv, and the architecture uv was built for otherwise.
+#[cfg(windows)]
+fn get_launcher_bin(gui: bool, python_executable: &Path) -> Result<&'static [u8], Error> {
+    let host = LauncherArch::host()?;
+    let arch = LauncherArch::from_executable(python_executable).unwrap_or(host);
+    launcher_bin_for(gui, arch)
+        .or_else(|| launcher_bin_for(gui, host))
+        .ok_or(Error::UnsupportedWindowsArch(std::env::consts::ARCH))
+}
+
+/// Return the embedded launcher binary for the given architecture, if any.
+#[cfg(windows)]
+fn launcher_bin_for(gui: bool, arch: LauncherArch) -> Option<&'static [u8]> {
+    match arch {
+        #[cfg(target_arch = "x86")]
+        LauncherArch::X86 => Some(if gui {
+            LAUNCHER_I686_GUI
+        } else {
+            LAUNCHER_I686_CONSOLE
+        }),
+        #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+        LauncherArch::X86_64 => Some(if gui {
+            LAUNCHER_X86_64_GUI
+        } else {
+            LAUNCHER_X86_64_CONSOLE
+        }),
+        #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+        LauncherArch::Aarch64 => Some(if gui {
+            LAUNCHER_AARCH64_GUI
+        } else {
+            LAUNCHER_AARCH64_CONSOLE
+        }),
+        _ => None,
+    }
+}
+
+/// Whether the launcher `image` was built from `launcher_bin`, judged by their code.
+///
+/// Embedding resources rewrites the resource section and the headers, but leaves the code
+/// untouched, so a launcher built from another binary, be it for another architecture or from an
+/// older version of uv, has different code.
+#[cfg(windows)]
+fn has_same_code(image: &[u8], launcher_bin: &[u8]) -> bool {
+    fn code(image: &[u8]) -> Option<&[u8]> {
+        let pe = goblin::pe::PE::parse(image).ok()?;
+        let text = pe
+            .sections
+            .iter()
+            .find(|section| section.name().is_ok_and(|name| name == ".text"))?;
+        let start = usize::try_from(text.pointer_to_raw_data).ok()?;
+        let len = usize::try_from(text.size_of_raw_data).ok()?;
+        image.get(start..start.checked_add(len)?)
+    }
+    code(image).is_some_and(|code_image| code(launcher_bin) == Some(code_image))
 }
 
 /// Helper to write Windows PE resources
@@ -355,6 +697,30 @@ fn read_resource(
     }
 }
 
+/// Encode the Python executable path for the [`RESOURCE_PYTHON_PATH`] resource, as UTF-8.
+///
+/// Paths that aren't valid Unicode, e.g., with unpaired surrogates, can't be represented as UTF-8,
+/// and are rejected.
+#[cfg(windows)]
+fn python_path_resource(python: &Path) -> Result<Vec<u8>, Error> {
+    let python = python.simplified();
+    let Some(python_path) = python.to_str() else {
+        return Err(Error::NonUnicodePythonPath(python.to_path_buf()));
+    };
+    Ok(python_path.as_bytes().to_vec())
+}
+
+/// Read the Python executable path from a trampoline.
+#[cfg(windows)]
+fn read_python_path(handle: windows::Win32::Foundation::HMODULE) -> Result<Option<PathBuf>, Error> {
+    let Some(path_data) = read_resource(handle, RESOURCE_PYTHON_PATH) else {
+        return Ok(None);
+    };
+    let python_path =
+        String::from_utf8(path_data).map_err(|err| Error::InvalidPath(err.utf8_error()))?;
+    Ok(Some(PathBuf::from(python_path)))
+}
+
 /// Construct a Windows script launcher.
 ///
 /// On Unix, this always returns [`Error::NotWindows`]. Trampolines are a Windows-specific feature
@@ -385,9 +751,7 @@ pub fn windows_script_launcher(
     use zip::ZipWriter;
     use zip::write::SimpleFileOptions;
 
-    use uv_fs::Simplified;
-
-    let launcher_bin: &[u8] = get_launcher_bin(is_gui)?;
+    let launcher_bin: &[u8] = get_launcher_bin(is_gui, python_executable.as_ref())?;
 
     let mut payload: Vec<u8> = Vec::new();
     {
@@ -405,8 +769,7 @@ pub fn windows_script_launcher(
         archive.finish().expect(error_msg);
     }
 
-    let python = python_executable.as_ref();
-    let python_path = python.simplified_display().to_string();
+    let python_path = python_path_resource(python_executable.as_ref())?;
 
     // Start with base launcher binary
     // Create temporary file for the launcher
@@ -422,8 +785,9 @@ pub fn windows_script_launcher(
             RESOURCE_TRAMPOLINE_KIND,
             &[LauncherKind::Script.to_resource_value()][..],
         ),
-        (RESOURCE_PYTHON_PATH, python_path.as_bytes()),
+        (RESOURCE_PYTHON_PATH, &python_path[..]),
         (RESOURCE_SCRIPT_DATA, &payload),
+        (RESOURCE_TRAMPOLINE_VERSION, &LATEST_FORMAT[..]),
     ];
     write_resources(&temp_file, resources)?;
 
@@ -459,12 +823,9 @@ pub fn windows_python_launcher(
     python_executable: impl AsRef<Path>,
     is_gui: bool,
 ) -> Result<Vec<u8>, Error> {
-    use uv_fs::Simplified;
+    let launcher_bin: &[u8] = get_launcher_bin(is_gui, python_executable.as_ref())?;
 
-    let launcher_bin: &[u8] = get_launcher_bin(is_gui)?;
-
-    let python = python_executable.as_ref();
-    let python_path = python.simplified_display().to_string();
+    let python_path = python_path_resource(python_executable.as_ref())?;
 
     // Create temporary file for the launcher
     let temp_dir = tempfile::TempDir::new()?;
@@ -479,7 +840,8 @@ pub fn windows_python_launcher(
             RESOURCE_TRAMPOLINE_KIND,
             &[LauncherKind::Python.to_resource_value()][..],
         ),
-        (RESOURCE_PYTHON_PATH, python_path.as_bytes()),
+        (RESOURCE_PYTHON_PATH, &python_path[..]),
+        (RESOURCE_TRAMPOLINE_VERSION, &LATEST_FORMAT[..]),
     ];
     write_resources(&temp_file, resources)?;
 
@@ -490,13 +852,27 @@ pub fn windows_python_launcher(
     Ok(launcher)
 }
 
+/// Resolve a relative embedded Python path against the directory of the launcher at `launcher`.
+fn resolve_relative_to(python_path: &Path, launcher: &Path) -> PathBuf {
+    if python_path.is_absolute() {
+        python_path.to_path_buf()
+    } else {
+        launcher
+            .parent()
+            .map(|parent| parent.join(python_path))
+            .unwrap_or_else(|| python_path.to_path_buf())
+    }
+}
+
 #[cfg(all(test, windows))]
 #[expect(clippy::print_stdout)]
 mod test {
+    use std::ffi::OsStr;
     use std::io::Write;
     use std::path::Path;
     use std::path::PathBuf;
-    use std::process::Command;
+    use std::process::{Command, Stdio};
+    use std::time::{Duration, Instant};
 
     use anyhow::Result;
     use assert_cmd::prelude::OutputAssertExt;
@@ -505,7 +881,11 @@ mod test {
 
     use which::which;
 
-    use super::{Launcher, LauncherKind, windows_python_launcher, windows_script_launcher};
+    use super::{
+        Error, LATEST_FORMAT, Launcher, LauncherFormat, LauncherKind, RESOURCE_PYTHON_PATH,
+        RESOURCE_TRAMPOLINE_KIND, RESOURCE_TRAMPOLINE_VERSION, get_launcher_bin, has_same_code,
+        windows_python_launcher, windows_script_launcher, write_resources,
+    };
 
     #[test]
     #[cfg(all(windows, target_arch = "x86", feature = "production"))]
@@ -555,6 +935,38 @@ mod test {
         );
     }
 
+    #[test]
+    #[cfg(all(windows, feature = "production"))]
+    fn test_launchers_skip_integer_formatting() {
+        // `core::fmt` formats integers with a lookup table of two-digit pairs, which is only
+        // linked if the launcher formats an integer with `Display`. The launchers write numbers
+        // with `ufmt` or by hand instead, such that a successful launch doesn't carry the
+        // formatting machinery.
+        const DIGIT_PAIRS: &[u8] = b"00010203040506070809";
+
+        #[cfg(target_arch = "x86")]
+        let launchers = [
+            ("GUI launcher", super::LAUNCHER_I686_GUI),
+            ("CLI launcher", super::LAUNCHER_I686_CONSOLE),
+        ];
+        #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+        let launchers = [
+            ("x86_64 GUI launcher", super::LAUNCHER_X86_64_GUI),
+            ("x86_64 CLI launcher", super::LAUNCHER_X86_64_CONSOLE),
+            ("aarch64 GUI launcher", super::LAUNCHER_AARCH64_GUI),
+            ("aarch64 CLI launcher", super::LAUNCHER_AARCH64_CONSOLE),
+        ];
+
+        for (name, launcher) in launchers {
+            assert!(
+                !launcher
+                    .windows(DIGIT_PAIRS.len())
+                    .any(|window| window == DIGIT_PAIRS),
+                "{name} links `core::fmt` integer formatting"
+            );
+        }
+    }
+
     /// Utility script for the test.
     fn get_script_launcher(shebang: &str, is_gui: bool) -> String {
         if is_gui {
@@ -786,6 +1198,846 @@ if __name__ == "__main__":
         Ok(())
     }
 
+    #[test]
+    fn console_script_launcher_unicode_arguments() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let console_bin_path = temp_dir.child("launcher.console.exe");
+
+        let python_executable_path = which("python")?;
+        let script = format!(
+            "{}\nimport sys\nsys.stdout.buffer.write(sys.argv[1].encode('utf-8'))\n",
+            format_shebang(&python_executable_path)
+        );
+        let console_launcher = windows_script_launcher(&script, false, &python_executable_path)?;
+        File::create(console_bin_path.path())?.write_all(console_launcher.as_ref())?;
+
+        // Characters outside of any single ANSI code page must reach Python unchanged.
+        let argument = "tëst–ファイル.txt";
+
+        Command::new(console_bin_path.path())
+            .arg(argument)
+            .assert()
+            .success()
+            .stdout(argument.as_bytes().to_vec());
+
+        Ok(())
+    }
+
+    #[test]
+    fn console_script_launcher_command_line_too_long() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let console_bin_path = temp_dir.child("launcher.console.exe");
+
+        let python_executable_path = which("python")?;
+        let script = format!(
+            "{}\nimport sys\nsys.stdout.buffer.write('\\n'.join(sys.argv[1:]).encode('utf-8'))\n",
+            format_shebang(&python_executable_path)
+        );
+        let console_launcher = windows_script_launcher(&script, false, &python_executable_path)?;
+        File::create(console_bin_path.path())?.write_all(console_launcher.as_ref())?;
+
+        // An argument that fits on the launcher's command line, i.e., after the quoted launcher
+        // path, but not on the child's, which also contains the Python path.
+        let argument = "a".repeat(32_760 - console_bin_path.path().as_os_str().len());
+        let output = Command::new(console_bin_path.path())
+            .arg(argument)
+            .output()?;
+        let stderr = String::from_utf8(output.stderr)?;
+        assert_eq!(output.status.code(), Some(1));
+        assert!(
+            stderr
+                .starts_with("error: uv trampoline failed to launch Python: the command line is "),
+            "{stderr}"
+        );
+        assert!(
+            stderr.contains("exceeding the Windows limit of 32767 characters"),
+            "{stderr}"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn python_launcher_unicode_arguments() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let console_bin_path = temp_dir.child("launcher.console.exe");
+
+        let python_executable_path = which("python")?;
+        let console_launcher = windows_python_launcher(&python_executable_path, false)?;
+        File::create(console_bin_path.path())?.write_all(console_launcher.as_ref())?;
+
+        // Mix non-ASCII characters with embedded quotes and backslashes, which must survive the
+        // command line rewrite regardless of the active code page.
+        let argument = r#"C:\Users\Пётр\data "数据" \"quoted\".csv"#;
+
+        Command::new(console_bin_path.path())
+            .arg("-c")
+            .arg("import sys; sys.stdout.buffer.write(sys.argv[1].encode('utf-8'))")
+            .arg(argument)
+            .assert()
+            .success()
+            .stdout(argument.as_bytes().to_vec());
+
+        Ok(())
+    }
+
+    /// Write a console Python launcher for the Python on `PATH`, reached through a junction to its
+    /// installation directory named `directory_name`.
+    fn python_launcher_through_junction(
+        temp_dir: &assert_fs::TempDir,
+        directory_name: &OsStr,
+    ) -> Result<(PathBuf, PathBuf)> {
+        let python_executable_path = which("python")?;
+        let python_home = python_executable_path
+            .parent()
+            .expect("Python executable should have a parent directory");
+        let junction = temp_dir.path().join(directory_name);
+        uv_fs::replace_symlink(python_home, &junction)?;
+        let python_path = junction.join(
+            python_executable_path
+                .file_name()
+                .expect("Python executable should have a file name"),
+        );
+
+        let console_bin_path = temp_dir.path().join("launcher.console.exe");
+        Launcher {
+            kind: LauncherKind::Python,
+            python_path: python_path.clone(),
+            script_data: None,
+            detach: false,
+            format: LauncherFormat::LATEST,
+        }
+        .write_to_file(&mut File::create(&console_bin_path)?, false)?;
+
+        Ok((console_bin_path, python_path))
+    }
+
+    #[test]
+    fn python_launcher_non_ascii_python_path() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let (console_bin_path, python_path) =
+            python_launcher_through_junction(&temp_dir, OsStr::new("Pythön 数据"))?;
+
+        Command::new(&console_bin_path)
+            .arg("-c")
+            .arg("print('Hello from Python Launcher')")
+            .assert()
+            .success()
+            .stdout("Hello from Python Launcher\r\n");
+
+        let launcher = Launcher::try_from_path(&console_bin_path)
+            .expect("We should succeed at reading the launcher")
+            .expect("The launcher should be valid");
+        assert_eq!(launcher.python_path, python_path);
+
+        Ok(())
+    }
+
+    #[test]
+    fn python_launcher_non_unicode_python_path() -> Result<()> {
+        use std::ffi::OsString;
+        use std::os::windows::ffi::OsStringExt;
+
+        // An unpaired surrogate is a valid path component on Windows, but has no UTF-8
+        // representation, which the bundled trampolines require.
+        let directory_name = "python-"
+            .encode_utf16()
+            .chain([0xD800])
+            .chain("-数据".encode_utf16())
+            .collect::<Vec<_>>();
+        let temp_dir = assert_fs::TempDir::new()?;
+        let err =
+            python_launcher_through_junction(&temp_dir, &OsString::from_wide(&directory_name))
+                .expect_err("Non-Unicode Python paths should be rejected");
+        assert!(matches!(
+            err.downcast_ref::<Error>(),
+            Some(Error::NonUnicodePythonPath(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn launcher_round_trip() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let bin_path = temp_dir.child("launcher.exe");
+
+        // The longest path Windows supports is 32,767 UTF-16 code units.
+        let long_path = PathBuf::from(format!(r"C:\{}\python.exe", "a".repeat(32_767 - 14)));
+        assert_eq!(long_path.as_os_str().len(), 32_767);
+
+        for python_path in [
+            PathBuf::from(r"C:\Python312\python.exe"),
+            PathBuf::from(r"..\python\python.exe"),
+            long_path,
+        ] {
+            for kind in [LauncherKind::Script, LauncherKind::Python] {
+                let launcher = Launcher {
+                    kind,
+                    python_path: python_path.clone(),
+                    script_data: None,
+                    detach: false,
+                    format: LauncherFormat::LATEST,
+                };
+                let mut bytes = Vec::new();
+                launcher.write_to(&mut bytes, false)?;
+                fs_err::write(bin_path.path(), &bytes)?;
+
+                let launcher = Launcher::try_from_path(bin_path.path())
+                    .expect("We should succeed at reading the launcher")
+                    .expect("The launcher should be valid");
+                assert_eq!(launcher.kind, kind);
+                assert_eq!(launcher.python_path, python_path);
+                assert_eq!(launcher.format, LauncherFormat::LATEST);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn launcher_retarget() -> Result<()> {
+        use std::ffi::OsString;
+        use std::os::windows::ffi::OsStringExt;
+
+        let temp_dir = assert_fs::TempDir::new()?;
+        let console_bin_path = temp_dir.child("launcher.console.exe");
+        let python_executable_path = which("python")?;
+
+        let launcher_console_script =
+            get_script_launcher(&format_shebang(&python_executable_path), false);
+        let console_launcher =
+            windows_script_launcher(&launcher_console_script, false, &python_executable_path)?;
+        File::create(console_bin_path.path())?.write_all(console_launcher.as_ref())?;
+        let script_data = Launcher::try_from_path(console_bin_path.path())?
+            .expect("The launcher should be valid")
+            .script_data;
+
+        // Grow the path, and shrink it again.
+        let long_path = PathBuf::from(format!(r"C:\{}\python.exe", "a".repeat(1000)));
+        for python_path in [&long_path, &python_executable_path] {
+            Launcher::retarget(console_bin_path.path(), python_path)?;
+
+            let launcher = Launcher::try_from_path(console_bin_path.path())
+                .expect("We should succeed at reading the launcher")
+                .expect("The launcher should be valid");
+            assert_eq!(launcher.kind, LauncherKind::Script);
+            assert_eq!(&launcher.python_path, python_path);
+            assert_eq!(launcher.script_data, script_data);
+            assert_eq!(launcher.format, LauncherFormat::LATEST);
+        }
+
+        // Paths that aren't valid Unicode are rejected, and leave the launcher untouched.
+        let wide_path = PathBuf::from(OsString::from_wide(
+            &r"C:\python-"
+                .encode_utf16()
+                .chain([0xD800])
+                .chain(r"\python.exe".encode_utf16())
+                .collect::<Vec<_>>(),
+        ));
+        assert!(matches!(
+            Launcher::retarget(console_bin_path.path(), &wide_path),
+            Err(Error::NonUnicodePythonPath(_))
+        ));
+
+        // The retargeted launcher still runs the embedded script.
+        Command::new(console_bin_path.path())
+            .assert()
+            .success()
+            .stdout("Hello from uv-trampoline-console.exe\r\n")
+            .stderr("Hello from uv-trampoline-console.exe\r\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn launcher_retarget_outdated() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let python_executable_path = which("python")?;
+        let long_path = PathBuf::from(format!(r"C:\{}\python.exe", "a".repeat(100)));
+
+        // A launcher in the legacy format, i.e., without a version resource, is replaced with one
+        // in the latest format rather than patched.
+        let legacy = temp_dir.child("legacy.exe");
+        Launcher {
+            kind: LauncherKind::Python,
+            python_path: python_executable_path.clone(),
+            script_data: None,
+            detach: false,
+            format: LauncherFormat::Legacy,
+        }
+        .write_to_file(&mut File::create(legacy.path())?, false)?;
+        assert_eq!(
+            LauncherFormat::from_path(legacy.path())?,
+            Some(LauncherFormat::Legacy)
+        );
+
+        Launcher::retarget(legacy.path(), &long_path)?;
+        let launcher =
+            Launcher::try_from_path(legacy.path())?.expect("The launcher should be valid");
+        assert_eq!(launcher.python_path, long_path);
+        assert_eq!(launcher.format, LauncherFormat::LATEST);
+
+        // A launcher built from another binary, e.g., by an older version of uv, is rewritten with
+        // the current binary. Simulate one by changing the last byte of its code.
+        let outdated = temp_dir.child("outdated.exe");
+        let mut contents = windows_python_launcher(&python_executable_path, false)?;
+        let launcher_bin = get_launcher_bin(false, &python_executable_path)?;
+        assert!(has_same_code(&contents, launcher_bin));
+        let pe = goblin::pe::PE::parse(&contents)?;
+        let text = pe
+            .sections
+            .iter()
+            .find(|section| section.name().is_ok_and(|name| name == ".text"))
+            .expect("The launcher should have code");
+        let last = text.pointer_to_raw_data as usize + text.size_of_raw_data as usize - 1;
+        contents[last] ^= 0xFF;
+        assert!(!has_same_code(&contents, launcher_bin));
+        fs_err::write(outdated.path(), &contents)?;
+
+        Launcher::retarget(outdated.path(), &python_executable_path)?;
+        assert!(has_same_code(&fs_err::read(outdated.path())?, launcher_bin));
+        Command::new(outdated.path())
+            .arg("-c")
+            .arg("print('retargeted')")
+            .assert()
+            .success()
+            .stdout("retargeted\r\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn launcher_retarget_not_a_launcher() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let python_executable_path = which("python")?;
+
+        // An executable that isn't a trampoline.
+        let executable = temp_dir.child("python.exe");
+        fs_err::copy(&python_executable_path, executable.path())?;
+        let contents = fs_err::read(executable.path())?;
+        assert!(matches!(
+            Launcher::retarget(executable.path(), &python_executable_path),
+            Err(Error::NotALauncher(_))
+        ));
+        assert_eq!(fs_err::read(executable.path())?, contents);
+
+        // A file that isn't an executable.
+        let text = temp_dir.child("launcher.exe");
+        fs_err::write(text.path(), b"not an executable")?;
+        assert!(matches!(
+            Launcher::retarget(text.path(), &python_executable_path),
+            Err(Error::NotALauncher(_))
+        ));
+        assert_eq!(fs_err::read(text.path())?, b"not an executable");
+
+        // A file that doesn't exist.
+        assert!(matches!(
+            Launcher::retarget(
+                &temp_dir.path().join("missing.exe"),
+                &python_executable_path
+            ),
+            Err(Error::NotALauncher(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn launcher_dangling_python_path() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let python_executable_path = which("python")?;
+        let bin_path = temp_dir.child("launcher.exe");
+
+        // A launcher for an existing interpreter isn't dangling.
+        File::create(bin_path.path())?
+            .write_all(&windows_python_launcher(&python_executable_path, false)?)?;
+        assert_eq!(Launcher::dangling_python_path(bin_path.path()), None);
+
+        // A launcher for a missing interpreter is, and reports the resolved path.
+        let missing = temp_dir.path().join("missing").join("python.exe");
+        Launcher::retarget(bin_path.path(), &missing)?;
+        assert_eq!(
+            Launcher::dangling_python_path(bin_path.path()),
+            Some(missing)
+        );
+
+        // Relative paths resolve against the launcher's directory.
+        Launcher::retarget(bin_path.path(), Path::new(r"python\python.exe"))?;
+        assert_eq!(
+            Launcher::dangling_python_path(bin_path.path()),
+            Some(temp_dir.path().join(r"python\python.exe"))
+        );
+        fs_err::create_dir_all(temp_dir.path().join("python"))?;
+        fs_err::copy(
+            &python_executable_path,
+            temp_dir.path().join(r"python\python.exe"),
+        )?;
+        assert_eq!(Launcher::dangling_python_path(bin_path.path()), None);
+
+        // Files that aren't launchers are never dangling.
+        assert_eq!(
+            Launcher::dangling_python_path(&python_executable_path),
+            None
+        );
+        assert_eq!(
+            Launcher::dangling_python_path(&temp_dir.path().join("missing.exe")),
+            None
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn launcher_resolve_python_path() {
+        let launcher = Launcher {
+            kind: LauncherKind::Python,
+            python_path: PathBuf::from(r"..\python\python.exe"),
+            script_data: None,
+            detach: false,
+            format: LauncherFormat::LATEST,
+        };
+        assert_eq!(
+            launcher.resolve_python_path(Path::new(r"C:\venv\Scripts\python.exe")),
+            PathBuf::from(r"C:\venv\Scripts\..\python\python.exe")
+        );
+
+        // Absolute paths are used as-is.
+        let launcher = launcher.with_python_path(PathBuf::from(r"C:\Python312\python.exe"));
+        assert_eq!(
+            launcher.resolve_python_path(Path::new(r"C:\venv\Scripts\python.exe")),
+            PathBuf::from(r"C:\Python312\python.exe")
+        );
+    }
+
+    #[test]
+    fn launcher_kind_detect() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let bin_path = temp_dir.child("launcher.exe");
+
+        for kind in [LauncherKind::Script, LauncherKind::Python] {
+            let launcher = Launcher {
+                kind,
+                python_path: PathBuf::from(r"C:\Python312\python.exe"),
+                script_data: None,
+                detach: false,
+                format: LauncherFormat::LATEST,
+            };
+            let mut bytes = Vec::new();
+            launcher.write_to(&mut bytes, false)?;
+            fs_err::write(bin_path.path(), &bytes)?;
+
+            assert_eq!(LauncherKind::detect(bin_path.path()), Some(kind));
+        }
+
+        // An executable that isn't a trampoline.
+        assert_eq!(LauncherKind::detect(&std::env::current_exe()?), None);
+        // A file that isn't an executable.
+        fs_err::write(bin_path.path(), b"not an executable")?;
+        assert_eq!(LauncherKind::detect(bin_path.path()), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn launcher_format() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let python_executable_path = which("python")?;
+
+        // Write a Python launcher with the given (raw) format version resource.
+        let write_launcher = |name: &str, version: Option<&[u8]>| -> Result<PathBuf> {
+            let path = temp_dir.child(name).to_path_buf();
+            fs_err::write(&path, get_launcher_bin(false, &python_executable_path)?)?;
+            let python_path = python_executable_path
+                .to_str()
+                .expect("Python path should be UTF-8")
+                .as_bytes();
+            let kind = [LauncherKind::Python.to_resource_value()];
+            let mut resources = vec![
+                (RESOURCE_TRAMPOLINE_KIND, &kind[..]),
+                (RESOURCE_PYTHON_PATH, python_path),
+            ];
+            if let Some(version) = version {
+                resources.push((RESOURCE_TRAMPOLINE_VERSION, version));
+            }
+            write_resources(&path, &resources)?;
+            Ok(path)
+        };
+
+        // Launchers without a version resource are legacy launchers, and still work.
+        let legacy = write_launcher("legacy.exe", None)?;
+        assert_eq!(
+            LauncherFormat::from_path(&legacy)?,
+            Some(LauncherFormat::Legacy)
+        );
+        assert!(LauncherFormat::Legacy.is_stale());
+        Command::new(&legacy)
+            .arg("-c")
+            .arg("print('legacy')")
+            .assert()
+            .success()
+            .stdout("legacy\r\n");
+
+        let v1 = write_launcher("v1.exe", Some(&LATEST_FORMAT))?;
+        assert_eq!(LauncherFormat::from_path(&v1)?, Some(LauncherFormat::V1));
+        assert!(!LauncherFormat::V1.is_stale());
+
+        // Launchers from a newer version of uv are reported as such, by uv and by the trampoline.
+        let newer = write_launcher("newer.exe", Some(&[2]))?;
+        assert!(matches!(
+            LauncherFormat::from_path(&newer),
+            Err(Error::UnsupportedLauncherFormat(2))
+        ));
+        let output = Command::new(&newer).arg("-c").arg("pass").output()?;
+        assert!(!output.status.success());
+        let stderr = String::from_utf8(output.stderr)?;
+        assert!(
+            stderr.contains("created by a newer version of uv"),
+            "{stderr}"
+        );
+
+        // A corrupted version resource is rejected.
+        let corrupted = write_launcher("corrupted.exe", Some(&[1, 0]))?;
+        assert!(matches!(
+            LauncherFormat::from_path(&corrupted),
+            Err(Error::UnprocessableMetadata)
+        ));
+
+        // Non-launchers have no format.
+        assert_eq!(LauncherFormat::from_path(&python_executable_path)?, None);
+
+        Ok(())
+    }
+
+    /// Return the number of bytes after the end of the last section of a PE image.
+    ///
+    /// Data after the last section is outside the PE image, which strict Authenticode
+    /// verification policies reject.
+    fn overlay_len(launcher: &[u8]) -> Result<usize> {
+        let pe = goblin::pe::PE::parse(launcher)?;
+        let image_end = pe
+            .sections
+            .iter()
+            .map(|section| section.pointer_to_raw_data as usize + section.size_of_raw_data as usize)
+            .max()
+            .unwrap_or_default();
+        Ok(launcher.len().saturating_sub(image_end))
+    }
+
+    #[test]
+    fn launcher_overlay() -> Result<()> {
+        let python_executable_path = which("python")?;
+
+        // Python launchers store their metadata in resources, within the image, so they can be
+        // signed.
+        let python_launcher = windows_python_launcher(&python_executable_path, false)?;
+        assert_eq!(overlay_len(&python_launcher)?, 0);
+
+        // Script launchers append the script as a zip archive after the image.
+        let launcher_script = get_script_launcher(&format_shebang(&python_executable_path), false);
+        let script_launcher =
+            windows_script_launcher(&launcher_script, false, &python_executable_path)?;
+        assert!(overlay_len(&script_launcher)? > 0);
+
+        Ok(())
+    }
+
+    /// Return the machine type from the COFF header of a PE image.
+    fn pe_machine(image: &[u8]) -> Result<u16> {
+        Ok(goblin::pe::header::Header::parse(image)?
+            .coff_header
+            .machine)
+    }
+
+    #[test]
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    fn launcher_matches_interpreter_arch() -> Result<()> {
+        use goblin::pe::header::{COFF_MACHINE_ARM64, COFF_MACHINE_X86_64};
+
+        let temp_dir = assert_fs::TempDir::new()?;
+        let python = temp_dir.child("python.exe");
+
+        // The embedded launchers double as interpreters of the respective architecture.
+        for (interpreter, machine) in [
+            (super::LAUNCHER_X86_64_CONSOLE, COFF_MACHINE_X86_64),
+            (super::LAUNCHER_AARCH64_CONSOLE, COFF_MACHINE_ARM64),
+        ] {
+            fs_err::write(python.path(), interpreter)?;
+
+            let launcher = windows_python_launcher(python.path(), false)?;
+            assert_eq!(pe_machine(&launcher)?, machine);
+            let launcher = windows_python_launcher(python.path(), true)?;
+            assert_eq!(pe_machine(&launcher)?, machine);
+            let launcher = windows_script_launcher("import sys\n", false, python.path())?;
+            assert_eq!(pe_machine(&launcher)?, machine);
+        }
+
+        // If the interpreter can't be read, the launcher matches uv.
+        let host = if cfg!(target_arch = "aarch64") {
+            COFF_MACHINE_ARM64
+        } else {
+            COFF_MACHINE_X86_64
+        };
+        let launcher = windows_python_launcher(temp_dir.child("missing.exe").path(), false)?;
+        assert_eq!(pe_machine(&launcher)?, host);
+
+        Ok(())
+    }
+
+    #[test]
+    fn gui_launcher_detach() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let gui_bin_path = temp_dir.child("launcher.gui.exe");
+        let marker = temp_dir.child("marker");
+
+        let pythonw_executable_path = which("pythonw")?;
+        let gui_launcher = windows_python_launcher(&pythonw_executable_path, true)?;
+        File::create(gui_bin_path.path())?.write_all(gui_launcher.as_ref())?;
+        assert!(Launcher::detach_gui(gui_bin_path.path())?);
+
+        let launcher = Launcher::try_from_path(gui_bin_path.path())
+            .expect("We should succeed at reading the launcher")
+            .expect("The launcher should be valid");
+        assert!(launcher.detach);
+
+        // The launcher exits while the child is still sleeping, without its exit code.
+        let start = Instant::now();
+        let status = Command::new(gui_bin_path.path())
+            .arg("-c")
+            .arg("import sys, time; time.sleep(3); open(sys.argv[1], 'w').close(); sys.exit(7)")
+            .arg(marker.path())
+            .status()?;
+        assert_eq!(status.code(), Some(0));
+        assert!(start.elapsed() < Duration::from_secs(3));
+        assert!(!marker.path().exists());
+
+        // The child wasn't assigned to a job object, so it keeps running.
+        while !marker.path().exists() {
+            assert!(
+                start.elapsed() < Duration::from_secs(30),
+                "The detached child should have written the marker"
+            );
+            std::thread::sleep(Duration::from_millis(100));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn console_launcher_detach() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let console_bin_path = temp_dir.child("launcher.console.exe");
+
+        let python_executable_path = which("python")?;
+        let console_launcher = windows_python_launcher(&python_executable_path, false)?;
+        File::create(console_bin_path.path())?.write_all(console_launcher.as_ref())?;
+
+        // Console launchers are left as-is, and can't be written detached.
+        assert!(!Launcher::detach_gui(console_bin_path.path())?);
+        let launcher = Launcher::try_from_path(console_bin_path.path())
+            .expect("We should succeed at reading the launcher")
+            .expect("The launcher should be valid");
+        assert!(!launcher.detach);
+        let err = launcher
+            .with_detach()
+            .write_to(&mut Vec::new(), false)
+            .unwrap_err();
+        assert!(matches!(err, Error::DetachedConsoleLauncher), "{err}");
+
+        Command::new(console_bin_path.path())
+            .arg("-c")
+            .arg("import sys; sys.exit(7)")
+            .assert()
+            .code(7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn python_launcher_missing_python() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let console_bin_path = temp_dir.child("shims").child("python.exe");
+        fs_err::create_dir_all(temp_dir.child("shims"))?;
+
+        // A launcher left behind on `PATH` after its environment was deleted.
+        let python_executable_path = temp_dir.child("deleted").child("python.exe");
+        let console_launcher = windows_python_launcher(python_executable_path.path(), false)?;
+        File::create(console_bin_path.path())?.write_all(console_launcher.as_ref())?;
+
+        let output = Command::new(console_bin_path.path()).output()?;
+        let stderr = String::from_utf8(output.stderr)?;
+
+        assert_eq!(output.status.code(), Some(1));
+        assert!(
+            stderr.starts_with(&format!(
+                "error: uv trampoline failed to find Python interpreter at `{}`",
+                python_executable_path.path().display()
+            )),
+            "{stderr}"
+        );
+        assert!(stderr.contains("moved or deleted"), "{stderr}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn grandchild_outlives_launcher() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let console_bin_path = temp_dir.child("launcher.console.exe");
+        let marker = temp_dir.child("marker");
+
+        let python_executable_path = which("python")?;
+        let console_launcher = windows_python_launcher(&python_executable_path, false)?;
+        File::create(console_bin_path.path())?.write_all(console_launcher.as_ref())?;
+
+        // The child starts a background process that writes the marker after a delay, and exits
+        // immediately. The standard streams are discarded, so we don't wait for the background
+        // process to close them.
+        let status = Command::new(console_bin_path.path())
+            .arg("-c")
+            .arg(concat!(
+                "import subprocess, sys\n",
+                "subprocess.Popen([sys.argv[1], '-c', ",
+                "'import sys, time; time.sleep(2); open(sys.argv[1], \"w\").close()', ",
+                "sys.argv[2]])\n",
+            ))
+            .arg(&python_executable_path)
+            .arg(marker.path())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()?;
+        assert!(status.success());
+        assert!(!marker.path().exists());
+
+        // The background process broke away from the launcher's job object, so it isn't
+        // terminated when the launcher exits.
+        let start = Instant::now();
+        while !marker.path().exists() {
+            assert!(
+                start.elapsed() < Duration::from_secs(30),
+                "The background process should have written the marker"
+            );
+            std::thread::sleep(Duration::from_millis(100));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn python_launcher_ntstatus_exit_code() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let console_bin_path = temp_dir.child("launcher.console.exe");
+
+        let python_executable_path = which("python")?;
+        let console_launcher = windows_python_launcher(&python_executable_path, false)?;
+        File::create(console_bin_path.path())?.write_all(console_launcher.as_ref())?;
+
+        // Exit with `STATUS_ACCESS_VIOLATION`, as a crashing child would.
+        let output = Command::new(console_bin_path.path())
+            .arg("-c")
+            .arg("import ctypes; ctypes.windll.kernel32.ExitProcess(ctypes.c_uint(0xC0000005))")
+            .output()?;
+        assert_eq!(
+            output.status.code().map(i32::cast_unsigned),
+            Some(0xC000_0005)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn python_launcher_ctrl_break_exit_code() -> Result<()> {
+        use std::io::{BufRead, BufReader};
+        use std::os::windows::process::CommandExt;
+
+        use windows::Win32::System::Console::{CTRL_BREAK_EVENT, GenerateConsoleCtrlEvent};
+        use windows::Win32::System::Threading::CREATE_NEW_PROCESS_GROUP;
+
+        let temp_dir = assert_fs::TempDir::new()?;
+        let console_bin_path = temp_dir.child("launcher.console.exe");
+
+        let python_executable_path = which("python")?;
+        let console_launcher = windows_python_launcher(&python_executable_path, false)?;
+        File::create(console_bin_path.path())?.write_all(console_launcher.as_ref())?;
+
+        // Run the launcher in its own process group, so that the event doesn't reach the test
+        // runner. Ctrl+C is disabled in new process groups, so send Ctrl+Break, which Python
+        // doesn't handle either.
+        let mut child = Command::new(console_bin_path.path())
+            .arg("-c")
+            .arg("import time; print('ready', flush=True); time.sleep(60)")
+            .stdout(Stdio::piped())
+            .creation_flags(CREATE_NEW_PROCESS_GROUP.0)
+            .spawn()?;
+        let mut line = String::new();
+        BufReader::new(child.stdout.take().expect("stdout is piped")).read_line(&mut line)?;
+        assert_eq!(line.trim(), "ready");
+
+        // SAFETY: winapi call; the process group is the launcher's.
+        #[allow(unsafe_code)]
+        unsafe { GenerateConsoleCtrlEvent(CTRL_BREAK_EVENT, child.id()) }?;
+
+        // The launcher ignores the event and exits with the interrupted child's
+        // `STATUS_CONTROL_C_EXIT`.
+        let status = child.wait()?;
+        assert_eq!(status.code().map(i32::cast_unsigned), Some(0xC000_013A));
+
+        Ok(())
+    }
+
+    #[test]
+    fn python_launcher_working_directory() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let console_bin_path = temp_dir.child("launcher.console.exe");
+        let cwd = temp_dir.child("cwd");
+        fs_err::create_dir_all(cwd.path())?;
+
+        let python_executable_path = which("python")?;
+        let console_launcher = windows_python_launcher(&python_executable_path, false)?;
+        File::create(console_bin_path.path())?.write_all(console_launcher.as_ref())?;
+
+        // The child starts in our working directory, not the one the trampoline switches to.
+        let output = Command::new(console_bin_path.path())
+            .arg("-c")
+            .arg("import os; print(os.getcwd())")
+            .current_dir(cwd.path())
+            .output()?;
+        assert!(output.status.success());
+        assert_eq!(
+            fs_err::canonicalize(String::from_utf8(output.stdout)?.trim())?,
+            fs_err::canonicalize(cwd.path())?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn python_launcher_unicode_environment() -> Result<()> {
+        let temp_dir = assert_fs::TempDir::new()?;
+        let console_bin_path = temp_dir.child("launcher.console.exe");
+
+        let python_executable_path = which("python")?;
+        let console_launcher = windows_python_launcher(&python_executable_path, false)?;
+        File::create(console_bin_path.path())?.write_all(console_launcher.as_ref())?;
+
+        // Compare in Python, since the console encoding may not round-trip non-ASCII output.
+        let script = [
+            "import os",
+            "assert os.environ['UV_TEST_PRÉNOM'] == 'Zoë ✓ 🐍', ascii(os.environ.get('UV_TEST_PRÉNOM'))",
+            "assert os.environ['UV_TEST_EMPTY'] == '', ascii(os.environ.get('UV_TEST_EMPTY'))",
+        ]
+        .join("\n");
+        let output = Command::new(console_bin_path.path())
+            .arg("-c")
+            .arg(script)
+            .env("UV_TEST_PRÉNOM", "Zoë ✓ 🐍")
+            .env("UV_TEST_EMPTY", "")
+            .output()?;
+        assert!(output.status.success(), "{output:?}");
+
+        Ok(())
+    }
+
     #[test]
     #[ignore = "This test will spawn a GUI and wait until you close the window."]
     fn gui_launcher() -> Result<()> {
@@ -0,0 +1,266 @@
+//! End-to-end tests for the trampoline, using the prebuilt launchers and a fake Python interpreter.
+//!
+//! The test binary doubles as the fake interpreter: when started with [`FAKE_PYTHON`] set, it
+//! prints its arguments as a JSON array and exits with the code in [`FAKE_PYTHON_EXIT_CODE`]. This
+//! covers the trampoline's argument forwarding (`skip_one_argument` and `push_quoted_path`), its
+//! metadata reader, and exit code propagation together, without depending on a Python
+//! installation.
+//!
+//! Since the test binary has to run its own `main` when launched as the interpreter, it doesn't
+//! use the libtest harness.
+
+/// Set to make the test binary behave as the fake interpreter.
+#[cfg(windows)]
+const FAKE_PYTHON: &str = "UV_TEST_FAKE_PYTHON";
+
+/// The exit code of the fake interpreter, `0` if unset.
+#[cfg(windows)]
+const FAKE_PYTHON_EXIT_CODE: &str = "UV_TEST_FAKE_PYTHON_EXIT_CODE";
+
+/// If set, the fake interpreter prints the value of the environment variable with this name,
+/// rather than its arguments.
+#[cfg(windows)]
+const FAKE_PYTHON_VARIABLE: &str = "UV_TEST_FAKE_PYTHON_VARIABLE";
+
+/// A test case, run in order by [`main`].
+#[cfg(windows)]
+type Test = fn() -> anyhow::Result<()>;
+
+#[cfg(not(windows))]
+fn main() {}
+
+#[cfg(windows)]
+#[expect(clippy::print_stdout)]
+fn main() -> anyhow::Result<()> {
+    if std::env::var_os(FAKE_PYTHON).is_some() {
+        e2e::fake_python();
+    }
+
+    let tests: [(&str, Test); 7] = [
+        ("plain_arguments", e2e::plain_arguments),
+        ("quoted_arguments", e2e::quoted_arguments),
+        ("non_ascii_arguments", e2e::non_ascii_arguments),
+        ("exit_code", e2e::exit_code),
+        ("script_path", e2e::script_path),
+        ("pyvenv_launcher", e2e::pyvenv_launcher),
+        ("nested_pyvenv_launcher", e2e::nested_pyvenv_launcher),
+    ];
+    for (name, test) in tests {
+        print!("test {name} ... ");
+        test()?;
+        println!("ok");
+    }
+
+    Ok(())
+}
+
+#[cfg(windows)]
+mod e2e {
+    use std::io::Write;
+    use std::path::{Path, PathBuf};
+    use std::process::{Command, Output};
+
+    use anyhow::{Result, ensure};
+    use fs_err::File;
+
+    use uv_trampoline_builder::{windows_python_launcher, windows_script_launcher};
+
+    use super::{FAKE_PYTHON, FAKE_PYTHON_EXIT_CODE, FAKE_PYTHON_VARIABLE};
+
+    /// Print the arguments as a JSON array, or the requested environment variable as a JSON
+    /// string, and exit with the requested code.
+    #[expect(clippy::print_stdout, clippy::exit)]
+    pub(super) fn fake_python() -> ! {
+        if let Ok(name) = std::env::var(FAKE_PYTHON_VARIABLE) {
+            let value = std::env::var(name).ok();
+            println!("{}", serde_json::to_string(&value).unwrap());
+        } else {
+            let arguments = std::env::args().skip(1).collect::<Vec<_>>();
+            println!("{}", serde_json::to_string(&arguments).unwrap());
+        }
+        let code = std::env::var(FAKE_PYTHON_EXIT_CODE)
+            .ok()
+            .and_then(|code| code.parse().ok())
+            .unwrap_or(0);
+        std::process::exit(code);
+    }
+
+    /// Copy the fake interpreter into `directory`, returning its path.
+    fn install_fake_python(directory: &Path) -> Result<PathBuf> {
+        fs_err::create_dir_all(directory)?;
+        let python = directory.join("python.exe");
+        fs_err::copy(std::env::current_exe()?, &python)?;
+        Ok(python)
+    }
+
+    /// Write a launcher to `path`.
+    fn write_launcher(path: &Path, launcher: &[u8]) -> Result<()> {
+        File::create(path)?.write_all(launcher)?;
+        Ok(())
+    }
+
+    /// Run a launcher and return the arguments the fake interpreter received.
+    fn run(launcher: &Path, arguments: &[&str]) -> Result<(Output, Vec<String>)> {
+        let output = Command::new(launcher)
+            .args(arguments)
+            .env(FAKE_PYTHON, "1")
+            .output()?;
+        let received = serde_json::from_slice(&output.stdout).unwrap_or_default();
+        Ok((output, received))
+    }
+
+    /// Assert that a Python launcher forwards `arguments` unchanged.
+    fn assert_forwarded(arguments: &[&str]) -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let python = install_fake_python(&temp_dir.path().join("python"))?;
+        let launcher = temp_dir.path().join("launcher.exe");
+        write_launcher(&launcher, &windows_python_launcher(&python, false)?)?;
+
+        let (output, received) = run(&launcher, arguments)?;
+        ensure!(output.status.success(), "{output:?}");
+        ensure!(received == arguments, "{received:?} != {arguments:?}");
+        Ok(())
+    }
+
+    pub(super) fn plain_arguments() -> Result<()> {
+        assert_forwarded(&[])?;
+        assert_forwarded(&["foo", "bar", "-c", "print(1)"])
+    }
+
+    pub(super) fn quoted_arguments() -> Result<()> {
+        assert_forwarded(&[
+            "foo bar",
+            "",
+            "foo \"bar\"",
+            "foo 'bar'",
+            "trailing\\",
+            "back\\\\slashes\\\"quote",
+            "\ttab",
+        ])
+    }
+
+    pub(super) fn non_ascii_arguments() -> Result<()> {
+        assert_forwarded(&["tëst–ファイル.txt", "数据 with spaces"])
+    }
+
+    pub(super) fn exit_code() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let python = install_fake_python(&temp_dir.path().join("python"))?;
+        let launcher = temp_dir.path().join("launcher.exe");
+        write_launcher(&launcher, &windows_python_launcher(&python, false)?)?;
+
+        for code in [0, 1, 42, 255] {
+            let output = Command::new(&launcher)
+                .env(FAKE_PYTHON, "1")
+                .env(FAKE_PYTHON_EXIT_CODE, code.to_string())
+                .output()?;
+            ensure!(output.status.code() == Some(code), "{output:?}");
+        }
+        Ok(())
+    }
+
+    /// Run a launcher and return the value of `__PYVENV_LAUNCHER__` the fake interpreter saw.
+    fn pyvenv_launcher_of(launcher: &Path, inherited: Option<&Path>) -> Result<Option<String>> {
+        let mut command = Command::new(launcher);
+        command
+            .env(FAKE_PYTHON, "1")
+            .env(FAKE_PYTHON_VARIABLE, "__PYVENV_LAUNCHER__");
+        if let Some(inherited) = inherited {
+            command.env("__PYVENV_LAUNCHER__", inherited);
+        } else {
+            command.env_remove("__PYVENV_LAUNCHER__");
+        }
+        let output = command.output()?;
+        ensure!(output.status.success(), "{output:?}");
+        Ok(serde_json::from_slice(&output.stdout)?)
+    }
+
+    pub(super) fn pyvenv_launcher() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let python = install_fake_python(&temp_dir.path().join("python"))?;
+        let launcher = temp_dir.path().join("launcher.exe");
+        write_launcher(&launcher, &windows_python_launcher(&python, false)?)?;
+
+        // Without an outer launcher, the variable points at this launcher.
+        let value = pyvenv_launcher_of(&launcher, None)?;
+        ensure!(
+            value.as_deref().map(Path::new) == Some(&*launcher),
+            "{value:?}"
+        );
+
+        // An empty value is replaced.
+        let value = pyvenv_launcher_of(&launcher, Some(Path::new("")))?;
+        ensure!(
+            value.as_deref().map(Path::new) == Some(&*launcher),
+            "{value:?}"
+        );
+
+        // A value set by an outer launcher is kept.
+        let outer = temp_dir.path().join("outer.exe");
+        write_launcher(&outer, b"")?;
+        let value = pyvenv_launcher_of(&launcher, Some(&outer))?;
+        ensure!(
+            value.as_deref().map(Path::new) == Some(&*outer),
+            "{value:?}"
+        );
+
+        // Unless it points at a file that no longer exists.
+        let missing = temp_dir.path().join("missing.exe");
+        let value = pyvenv_launcher_of(&launcher, Some(&missing))?;
+        ensure!(
+            value.as_deref().map(Path::new) == Some(&*launcher),
+            "{value:?}"
+        );
+
+        Ok(())
+    }
+
+    pub(super) fn nested_pyvenv_launcher() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let python = install_fake_python(&temp_dir.path().join("python"))?;
+
+        // An outer launcher that points at an inner launcher, as with a virtual environment
+        // created from a uv-managed Python.
+        let inner = temp_dir.path().join("inner.exe");
+        write_launcher(&inner, &windows_python_launcher(&python, false)?)?;
+        let outer = temp_dir.path().join("outer.exe");
+        write_launcher(&outer, &windows_python_launcher(&inner, false)?)?;
+
+        // The executable the user invoked is reported, not the inner launcher.
+        let value = pyvenv_launcher_of(&outer, None)?;
+        ensure!(
+            value.as_deref().map(Path::new) == Some(&*outer),
+            "{value:?}"
+        );
+
+        Ok(())
+    }
+
+    pub(super) fn script_path() -> Result<()> {
+        let temp_dir = tempfile::tempdir()?;
+        let python = install_fake_python(&temp_dir.path().join("python"))?;
+
+        // The script itself is never run, since the interpreter is fake.
+        let directory = temp_dir.path().join("Scripts with spaces");
+        fs_err::create_dir_all(&directory)?;
+        let launcher = directory.join("launcher.exe");
+        write_launcher(
+            &launcher,
+            &windows_script_launcher("import sys\n", false, &python)?,
+        )?;
+
+        // The interpreter receives the launcher, which holds the zipped script, followed by our
+        // arguments.
+        let (output, received) = run(&launcher, &["foo bar", "baz"])?;
+        ensure!(output.status.success(), "{output:?}");
+        ensure!(
+            received.len() == 3 && received[1..] == ["foo bar", "baz"],
+            "{received:?}"
+        );
+        ensure!(
+            fs_err::canonicalize(&received[0])? == fs_err::canonicalize(&launcher)?,
+            "{received:?}"
+        );
+        Ok(())
+    }
+}
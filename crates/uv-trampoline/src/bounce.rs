@@ -1,61 +1,108 @@
-use std::ffi::CString;
+use std::os::windows::ffi::OsStrExt;
 use std::path::{Path, PathBuf};
 use std::vec::Vec;
 
 use windows::Win32::Foundation::{LPARAM, WPARAM};
 use windows::Win32::{
     Foundation::{
-        CloseHandle, HANDLE, HANDLE_FLAG_INHERIT, INVALID_HANDLE_VALUE, SetHandleInformation, TRUE,
+        CloseHandle, HANDLE, HANDLE_FLAG_INHERIT, INVALID_HANDLE_VALUE, MAX_PATH,
+        SetHandleInformation, TRUE, WAIT_FAILED, WAIT_TIMEOUT,
     },
     Storage::FileSystem::{FILE_TYPE_PIPE, GetFileType},
-    System::Console::{GetStdHandle, STD_INPUT_HANDLE, STD_OUTPUT_HANDLE, SetStdHandle},
-    System::Environment::GetCommandLineA,
+    System::Console::{
+        CONSOLE_MODE, GetConsoleMode, GetStdHandle, STD_INPUT_HANDLE, STD_OUTPUT_HANDLE,
+        SetStdHandle,
+    },
+    System::Environment::{GetCommandLineW, GetCurrentDirectoryW},
     System::LibraryLoader::{FindResourceW, LoadResource, LockResource, SizeofResource},
     System::Threading::{
-        CreateProcessA, GetExitCodeProcess, GetStartupInfoA, INFINITE, PROCESS_CREATION_FLAGS,
-        PROCESS_INFORMATION, STARTF_USESTDHANDLES, STARTUPINFOA, WaitForInputIdle,
-        WaitForSingleObject,
+        CREATE_SUSPENDED, CreateProcessW, ExitProcess, GetExitCodeProcess, GetStartupInfoW,
+        INFINITE, PROCESS_INFORMATION, ResumeThread, STARTF_USESTDHANDLES, STARTUPINFOW,
+        TerminateProcess, WaitForInputIdle, WaitForSingleObject,
     },
     UI::WindowsAndMessaging::{
         CreateWindowExA, DestroyWindow, GetMessageA, HWND_MESSAGE, MSG, PEEK_MESSAGE_REMOVE_TYPE,
         PeekMessageA, PostMessageA, WINDOW_EX_STYLE, WINDOW_STYLE,
     },
 };
-use windows::core::{PSTR, s};
+use windows::core::{PWSTR, s};
 
 use uv_windows::{Job, install_ctrl_handler};
 
 use uv_static::EnvVars;
 
-use crate::{error, format, warn};
+use crate::command_line::{
+    MAX_COMMAND_LINE_LENGTH, push_quoted_argument, push_quoted_program, skip_one_argument,
+};
+use crate::environment::{keep_pyvenv_launcher, should_set_python_home};
+use crate::metadata::{TrampolineKind, is_supported_version};
+use crate::venv::is_virtualenv;
+use crate::{diagnostics, error, format, warn};
 
 // https://learn.microsoft.com/en-us/windows/win32/menurc/resource-types
 const RT_RCDATA: u16 = 10;
 
+/// The time, in milliseconds, to wait for a GUI child to become idle, see
+/// [`clear_app_starting_state`].
+const INPUT_IDLE_TIMEOUT: u32 = 30_000;
+
+const SPACE: u16 = b' ' as u16;
+
 /// Resource IDs for the trampoline metadata
 const RESOURCE_TRAMPOLINE_KIND: windows::core::PCWSTR = windows::core::w!("UV_TRAMPOLINE_KIND");
 const RESOURCE_PYTHON_PATH: windows::core::PCWSTR = windows::core::w!("UV_PYTHON_PATH");
-
-/// The kind of trampoline.
-enum TrampolineKind {
-    /// The trampoline should execute itself, it's a zipped Python script.
-    Script,
-    /// The trampoline should just execute Python, it's a proxy Python executable.
-    Python,
+const RESOURCE_DETACH: windows::core::PCWSTR = windows::core::w!("UV_DETACH");
+const RESOURCE_TRAMPOLINE_VERSION: windows::core::PCWSTR =
+    windows::core::w!("UV_TRAMPOLINE_VERSION");
+
+/// An error reading the trampoline metadata from the executable's resources.
+#[derive(Debug)]
+enum TrampolineError {
+    /// The format version resource holds a version newer than
+    /// [`crate::metadata::TRAMPOLINE_VERSION`], or is malformed.
+    UnsupportedVersion,
+    /// The trampoline kind resource is missing.
+    MissingKind,
+    /// The trampoline kind resource holds an unknown kind.
+    UnknownKind,
+    /// The Python path resource is missing.
+    MissingPythonPath,
+    /// The Python path resource is not valid UTF-8.
+    InvalidUtf8,
+    /// A resource is present but could not be loaded.
+    Io(&'static str, std::io::Error),
 }
 
-impl TrampolineKind {
-    fn from_resource(data: &[u8]) -> Option<Self> {
-        match data.first() {
-            Some(1) => Some(Self::Script),
-            Some(2) => Some(Self::Python),
-            _ => None,
+impl TrampolineError {
+    fn message(&self) -> &'static str {
+        match self {
+            Self::UnsupportedVersion => {
+                "uv trampoline failed to load metadata: the launcher was created by a newer version of uv"
+            }
+            Self::MissingKind | Self::UnknownKind => {
+                "uv trampoline failed to load trampoline kind from resources"
+            }
+            Self::MissingPythonPath | Self::InvalidUtf8 => {
+                "uv trampoline failed to load Python path from resources"
+            }
+            Self::Io(message, _) => message,
+        }
+    }
+
+    /// Report the error and exit.
+    #[cold]
+    fn exit(self) -> ! {
+        match &self {
+            Self::Io(message, err) => print_error_and_exit(message, err),
+            _ => error_and_exit(self.message()),
         }
     }
 }
 
-/// Safely loads a resource from the current module
-fn load_resource(resource_id: windows::core::PCWSTR) -> Option<Vec<u8>> {
+/// Safely loads a resource from the current module.
+///
+/// Returns `Ok(None)` if the resource doesn't exist or is empty.
+fn load_resource(resource_id: windows::core::PCWSTR) -> std::io::Result<Option<Vec<u8>>> {
     // SAFETY: winapi calls; null-terminated strings; all pointers are checked.
     unsafe {
         // Find the resource
@@ -65,47 +112,80 @@ fn load_resource(resource_id: windows::core::PCWSTR) -> Option<Vec<u8>> {
             windows::core::PCWSTR(RT_RCDATA as *const _),
         );
         if resource.is_invalid() {
-            return None;
+            return Ok(None);
         }
 
         // Get resource size and data
         let size = SizeofResource(None, resource);
         if size == 0 {
-            return None;
+            return Ok(None);
         }
-        let data = LoadResource(None, resource).ok();
-        let ptr = LockResource(data?) as *const u8;
+        let data = LoadResource(None, resource)?;
+        let ptr = LockResource(data) as *const u8;
         if ptr.is_null() {
-            return None;
+            return Err(std::io::Error::last_os_error());
         }
 
         // Copy the resource data into a Vec
-        Some(std::slice::from_raw_parts(ptr, size as usize).to_vec())
+        Ok(Some(
+            std::slice::from_raw_parts(ptr, size as usize).to_vec(),
+        ))
+    }
+}
+
+/// Read the [`TrampolineKind`] and the embedded Python path from the executable's resources.
+///
+/// The executable's resources live in the image that the loader has already mapped, so this
+/// performs no file I/O. Caching the metadata in a file next to the launcher would add syscalls
+/// to every launch rather than remove them.
+fn read_trampoline_metadata() -> Result<(TrampolineKind, PathBuf), TrampolineError> {
+    // Check the format version first, since newer formats may change the other resources.
+    let version = load_resource(RESOURCE_TRAMPOLINE_VERSION).map_err(|err| {
+        TrampolineError::Io("uv trampoline failed to load metadata from resources", err)
+    })?;
+    if let Some(version) = version
+        && !is_supported_version(&version)
+    {
+        return Err(TrampolineError::UnsupportedVersion);
     }
+
+    let kind = load_resource(RESOURCE_TRAMPOLINE_KIND)
+        .map_err(|err| {
+            TrampolineError::Io(
+                "uv trampoline failed to load trampoline kind from resources",
+                err,
+            )
+        })?
+        .ok_or(TrampolineError::MissingKind)?;
+    let kind = TrampolineKind::from_resource(&kind).ok_or(TrampolineError::UnknownKind)?;
+
+    let python_path = load_resource(RESOURCE_PYTHON_PATH)
+        .map_err(|err| {
+            TrampolineError::Io(
+                "uv trampoline failed to load Python path from resources",
+                err,
+            )
+        })?
+        .ok_or(TrampolineError::MissingPythonPath)?;
+    let python_path = decode_python_path(python_path)?;
+
+    Ok((kind, python_path))
+}
+
+/// Decode a Python path stored as UTF-8.
+fn decode_python_path(data: Vec<u8>) -> Result<PathBuf, TrampolineError> {
+    String::from_utf8(data)
+        .map(PathBuf::from)
+        .map_err(|_| TrampolineError::InvalidUtf8)
 }
 
 /// Transform `<command> <arguments>` to `python <command> <arguments>` or `python <arguments>`
 /// depending on the [`TrampolineKind`].
-fn make_child_cmdline() -> CString {
+fn make_child_cmdline(trampoline_kind: TrampolineKind, python_path: PathBuf) -> Vec<u16> {
     let executable_name = std::env::current_exe().unwrap_or_else(|_| {
         error_and_exit("uv trampoline failed to determine executable path");
     });
 
-    // Load trampoline kind
-    let trampoline_kind = load_resource(RESOURCE_TRAMPOLINE_KIND)
-        .and_then(|data| TrampolineKind::from_resource(&data))
-        .unwrap_or_else(|| {
-            error_and_exit("uv trampoline failed to load trampoline kind from resources")
-        });
-
-    // Load Python path
-    let python_path = load_resource(RESOURCE_PYTHON_PATH)
-        .and_then(|data| String::from_utf8(data).ok())
-        .map(PathBuf::from)
-        .unwrap_or_else(|| {
-            error_and_exit("uv trampoline failed to load Python path from resources")
-        });
-
     let python_exe = if python_path.is_absolute() {
         python_path
     } else {
@@ -118,6 +198,12 @@ fn make_child_cmdline() -> CString {
         parent_dir.join(python_path)
     };
 
+    // Fail with an actionable error if the interpreter no longer exists, e.g., because the
+    // environment was moved or deleted, rather than with an opaque error from `CreateProcessW`.
+    if python_exe.symlink_metadata().is_err() {
+        missing_python_and_exit(&python_exe);
+    }
+
     let python_exe =
         if !python_exe.is_absolute() || matches!(trampoline_kind, TrampolineKind::Script) {
             // NOTICE: dunce adds 5kb~
@@ -132,9 +218,9 @@ fn make_child_cmdline() -> CString {
             python_exe
         };
 
-    let mut child_cmdline = Vec::<u8>::new();
-    push_quoted_path(python_exe.as_ref(), &mut child_cmdline);
-    child_cmdline.push(b' ');
+    let mut child_cmdline = Vec::<u16>::new();
+    push_quoted_program(python_exe.as_os_str().encode_wide(), &mut child_cmdline);
+    child_cmdline.push(SPACE);
 
     // Only execute the trampoline again if it's a script, otherwise, just invoke Python.
     match trampoline_kind {
@@ -147,7 +233,14 @@ fn make_child_cmdline() -> CString {
                 // the approach taken by CPython for Python Launchers
                 // (in `launcher.c`). This allows virtual environments to
                 // be correctly detected when using trampolines.
-                std::env::set_var(EnvVars::PYVENV_LAUNCHER, &executable_name);
+                //
+                // An outer launcher (e.g., the `py` launcher, or another
+                // trampoline) may have already set it to the executable the
+                // user invoked, in which case it's left alone.
+                let inherited = std::env::var_os(EnvVars::PYVENV_LAUNCHER);
+                if !keep_pyvenv_launcher(inherited.as_deref()) {
+                    std::env::set_var(EnvVars::PYVENV_LAUNCHER, &executable_name);
+                }
 
                 // If this is not a virtual environment, set `PYTHONHOME` to
                 // the parent directory of the executable. This ensures that
@@ -159,19 +252,10 @@ fn make_child_cmdline() -> CString {
                 // - Override inherited `PYTHONHOME` from parent Python processes
                 // - Preserve user-defined `PYTHONHOME` values
                 if !is_virtualenv(python_exe.as_path()) {
-                    let python_home = std::env::var(EnvVars::PYTHONHOME).ok();
-                    let marker = std::env::var(EnvVars::UV_INTERNAL__PYTHONHOME).ok();
-
-                    // Only set `PYTHONHOME` if:
-                    // - It's not set, OR
-                    // - It was set by uv (marker matches current `PYTHONHOME`)
-                    let should_override = match (&python_home, &marker) {
-                        (None, _) => true,
-                        (Some(home), Some(m)) if home == m => true,
-                        _ => false,
-                    };
-
-                    if should_override {
+                    let python_home = std::env::var_os(EnvVars::PYTHONHOME);
+                    let marker = std::env::var_os(EnvVars::UV_INTERNAL__PYTHONHOME);
+
+                    if should_set_python_home(python_home.as_deref(), marker.as_deref()) {
                         let home = python_exe
                             .parent()
                             .expect("Python executable should have a parent directory");
@@ -186,107 +270,38 @@ fn make_child_cmdline() -> CString {
             // when e.g. invoking `black` instead of `<PATH_TO_VENV>/Scripts/black` and Python then fails
             // to find the file. Unfortunately, this complicates things because we now need to split the executable
             // from the arguments string...
-            push_quoted_path(executable_name.as_ref(), &mut child_cmdline);
+            //
+            // Unlike the Python executable, the script is a regular argument to Python, so it's
+            // quoted following the rules for arguments rather than those for the program name.
+            push_quoted_argument(
+                executable_name.as_os_str().encode_wide(),
+                &mut child_cmdline,
+            );
         }
     }
 
     push_arguments(&mut child_cmdline);
 
-    child_cmdline.push(b'\0');
-
-    // Helpful when debugging trampoline issues
-    // warn!(
-    //     "executable_name: '{}'\nnew_cmdline: {}",
-    //     &*executable_name.to_string_lossy(),
-    //     std::str::from_utf8(child_cmdline.as_slice()).unwrap()
-    // );
-
-    CString::from_vec_with_nul(child_cmdline).unwrap_or_else(|_| {
-        error_and_exit("uv trampoline child command line is not correctly null terminated");
-    })
-}
-
-fn push_quoted_path(path: &Path, command: &mut Vec<u8>) {
-    command.push(b'"');
-    for byte in path.as_os_str().as_encoded_bytes() {
-        if *byte == b'"' {
-            // 3 double quotes: one to end the quoted span, one to become a literal double-quote,
-            // and one to start a new quoted span.
-            command.extend(br#"""""#);
-        } else {
-            command.push(*byte);
-        }
+    child_cmdline.push(0);
+    if child_cmdline.len() > MAX_COMMAND_LINE_LENGTH {
+        command_line_too_long_and_exit(child_cmdline.len());
     }
-    command.extend(br#"""#);
-}
 
-/// Checks if the given executable is part of a virtual environment
-///
-/// Checks if a `pyvenv.cfg` file exists in grandparent directory of the given executable.
-/// PEP 405 specifies a more robust procedure (checking both the parent and grandparent
-/// directory and then scanning for a `home` key), but in practice we have found this to
-/// be unnecessary.
-fn is_virtualenv(executable: &Path) -> bool {
-    executable
-        .parent()
-        .and_then(Path::parent)
-        .map(|path| path.join("pyvenv.cfg").is_file())
-        .unwrap_or(false)
+    child_cmdline
 }
 
-fn push_arguments(output: &mut Vec<u8>) {
-    // SAFETY: We rely on `GetCommandLineA` to return a valid pointer to a null terminated string.
-    let arguments_as_str = unsafe { GetCommandLineA() };
-    let arguments_as_bytes = unsafe { arguments_as_str.as_bytes() };
+fn push_arguments(output: &mut Vec<u16>) {
+    // SAFETY: We rely on `GetCommandLineW` to return a valid pointer to a null terminated string.
+    let arguments_as_str = unsafe { GetCommandLineW() };
+    let arguments_as_wide = unsafe { arguments_as_str.as_wide() };
 
-    // Skip over the executable name and then push the rest of the arguments
-    let after_executable = skip_one_argument(arguments_as_bytes);
+    // Skip over the executable name and then push the rest of the arguments. We operate on the
+    // UTF-16 command line directly, so that arguments outside the active code page are preserved.
+    let after_executable = skip_one_argument(arguments_as_wide);
 
     output.extend_from_slice(after_executable)
 }
 
-fn skip_one_argument(arguments: &[u8]) -> &[u8] {
-    let mut quoted = false;
-    let mut offset = 0;
-    let mut bytes_iter = arguments.iter().peekable();
-
-    // Implements https://learn.microsoft.com/en-us/cpp/c-language/parsing-c-command-line-arguments?view=msvc-170
-    while let Some(byte) = bytes_iter.next().copied() {
-        match byte {
-            b'"' => {
-                quoted = !quoted;
-            }
-            b'\\' => {
-                // Skip over escaped quotes or even number of backslashes.
-                if matches!(bytes_iter.peek().copied(), Some(&b'\"' | &b'\\')) {
-                    offset += 1;
-                    bytes_iter.next();
-                }
-            }
-            byte => {
-                if byte.is_ascii_whitespace() && !quoted {
-                    break;
-                }
-            }
-        }
-
-        offset += 1;
-    }
-
-    &arguments[offset..]
-}
-
-#[cold]
-fn print_job_error_and_exit(message: &str, err: uv_windows::JobError) -> ! {
-    error!(
-        "{}\n  Caused by: {} (os error {})",
-        message,
-        err.message(),
-        err.code()
-    );
-    exit_with_status(1);
-}
-
 #[cold]
 fn print_ctrl_handler_error_and_exit(err: uv_windows::CtrlHandlerError) -> ! {
     error!(
@@ -296,7 +311,8 @@ fn print_ctrl_handler_error_and_exit(err: uv_windows::CtrlHandlerError) -> ! {
     exit_with_status(1);
 }
 
-fn spawn_child(si: &STARTUPINFOA, child_cmdline: CString) -> HANDLE {
+/// Make the standard handles passed to us inheritable by the processes we spawn.
+fn inherit_std_handles(si: &STARTUPINFOW) {
     // See distlib/PC/launcher.c::run_child
     if (si.dwFlags & STARTF_USESTDHANDLES).0 != 0 {
         // ignore errors, if the handles are not inheritable/valid, then nothing we can do
@@ -307,40 +323,98 @@ fn spawn_child(si: &STARTUPINFOA, child_cmdline: CString) -> HANDLE {
         unsafe { SetHandleInformation(si.hStdError, HANDLE_FLAG_INHERIT.0, HANDLE_FLAG_INHERIT) }
             .unwrap_or_else(|_| warn!("Making stderr inheritable failed"));
     }
+}
+
+/// Spawn the Python child, returning its process and thread handles.
+///
+/// The child is created suspended, so that it can be assigned to the job object before it runs;
+/// it must be started with [`resume_child`].
+///
+/// If given, the child starts in `cwd`, a null-terminated path, rather than inheriting our working
+/// directory, so that it doesn't depend on when we switch away from it.
+fn spawn_child(
+    si: &STARTUPINFOW,
+    mut child_cmdline: Vec<u16>,
+    cwd: Option<&[u16]>,
+) -> std::io::Result<PROCESS_INFORMATION> {
     let mut child_process_info = PROCESS_INFORMATION::default();
     unsafe {
-        CreateProcessA(
+        CreateProcessW(
             None,
             // Why does this have to be mutable? Who knows. But it's not a mistake --
             // MS explicitly documents that this buffer might be mutated by CreateProcess.
-            Some(PSTR::from_raw(child_cmdline.as_ptr() as *mut _)),
+            Some(PWSTR::from_raw(child_cmdline.as_mut_ptr())),
             None,
             None,
             true,
-            PROCESS_CREATION_FLAGS(0),
-            None,
+            CREATE_SUSPENDED,
             None,
+            cwd.map_or(windows::core::PCWSTR::null(), |cwd| {
+                windows::core::PCWSTR(cwd.as_ptr())
+            }),
             si,
             &mut child_process_info,
         )
     }
-    .unwrap_or_else(|_| {
-        print_last_error_and_exit("uv trampoline failed to spawn Python child process");
-    });
+    .map_err(|_| std::io::Error::last_os_error())?;
+    Ok(child_process_info)
+}
+
+/// Spawn the Python child with [`spawn_child`] and assign it to `job`, if any, so that it gets
+/// terminated if the trampoline is killed.
+///
+/// Processes the child spawns silently break away from the job, so background processes it
+/// intentionally leaves running, e.g., a daemonized language server, outlive the trampoline. The
+/// child is still suspended, so it can't exit or spawn processes before it's assigned.
+///
+/// If the assignment fails, e.g., because we run in a job that can't be nested on Windows 7, the
+/// child may outlive the trampoline on forced kill, but normal execution (child exits naturally)
+/// is unaffected so we ignore the failure. This matches `distlib`'s approach where
+/// `AssignProcessToJobObject` failure is non-fatal [1].
+///
+/// See also <https://github.com/astral-sh/uv/pull/18170> which explores a more robust solution
+/// at the cost of increased complexity.
+///
+/// [1]: https://github.com/pypa/distlib/blob/37df85a61ead2ea2dc48d0e06f7bfe2f209a982c/PC/launcher.c#L835
+fn spawn_child_in_job(
+    si: &STARTUPINFOW,
+    child_cmdline: Vec<u16>,
+    cwd: Option<&[u16]>,
+    job: Option<&Job>,
+) -> std::io::Result<PROCESS_INFORMATION> {
+    let child_process_info = spawn_child(si, child_cmdline, cwd)?;
+    // SAFETY: The process handle is valid, as returned by `spawn_child`.
+    if let Some(Err(e)) = job.map(|job| unsafe { job.assign_process(child_process_info.hProcess) })
+    {
+        warn!(
+            "uv trampoline failed to assign child process to job object\n  Caused by: {} (os error {})",
+            e.message(),
+            e.code(),
+        );
+    }
+    Ok(child_process_info)
+}
+
+/// Start a child spawned by [`spawn_child`], closing its thread handle.
+fn resume_child(child_process_info: &PROCESS_INFORMATION) {
+    // SAFETY: The thread handle is valid, as returned by `CreateProcessW`.
+    if unsafe { ResumeThread(child_process_info.hThread) } == u32::MAX {
+        // Don't leave a suspended child behind.
+        let _ = unsafe { TerminateProcess(child_process_info.hProcess, 1) };
+        print_last_error_and_exit("uv trampoline failed to resume Python child process");
+    }
     unsafe { CloseHandle(child_process_info.hThread) }.unwrap_or_else(|_| {
         print_last_error_and_exit(
             "uv trampoline failed to close Python child process thread handle",
         );
     });
-    // Return handle to child process.
-    child_process_info.hProcess
 }
 
 // Apparently, the Windows C runtime has a secret way to pass file descriptors into child
 // processes, by using the .lpReserved2 field. We want to close those file descriptors too.
 // The UCRT source code has details on the memory layout (see also initialize_inherited_file_handles_nolock):
 // https://github.com/huangqinjin/ucrt/blob/10.0.19041.0/lowio/ioinit.cpp#L190-L223
-fn close_handles(si: &STARTUPINFOA) {
+fn close_handles(si: &STARTUPINFOW) {
     // See distlib/PC/launcher.c::cleanup_standard_io()
     // Unlike cleanup_standard_io(), we don't close STD_ERROR_HANDLE to retain warn!
     for std_handle in [STD_INPUT_HANDLE, STD_OUTPUT_HANDLE] {
@@ -399,6 +473,10 @@ fn close_handles(si: &STARTUPINFOA) {
     Why do we call `PostMessage`/`GetMessage` at the start, before waiting for the
     child? (Looking at the bpo issue above, this was originally the *whole* fix.)
     Is creating a window and calling PeekMessage the best way to do this? idk.
+
+    The wait for the child to become idle is bounded by `INPUT_IDLE_TIMEOUT`, since a child
+    that never processes window messages, e.g., one that hands off to a worker and leaves its GUI
+    thread idle, would otherwise keep us from ever waiting for it to exit.
 */
 fn clear_app_starting_state(child_handle: HANDLE) {
     let mut msg = MSG::default();
@@ -411,8 +489,15 @@ fn clear_app_starting_state(child_handle: HANDLE) {
             warn!("Failed to retrieve posted window message");
         }
         // Proxy the child's input idle event.
-        if WaitForInputIdle(child_handle, INFINITE) != 0 {
-            warn!("Failed to wait for input from window");
+        match WaitForInputIdle(child_handle, INPUT_IDLE_TIMEOUT) {
+            0 => {}
+            result if result == WAIT_TIMEOUT.0 => {
+                warn!(
+                    "Timed out after {}ms waiting for the child process to become idle",
+                    INPUT_IDLE_TIMEOUT
+                );
+            }
+            _ => warn!("Failed to wait for input from window"),
         }
         // Signal the process input idle event by creating a window and pumping
         // sent messages. The window class isn't important, so just use the
@@ -440,59 +525,114 @@ fn clear_app_starting_state(child_handle: HANDLE) {
     }
 }
 
+/// Whether to exit as soon as the child is running, rather than waiting for it to exit.
+///
+/// Enabled by the `UV_DETACH` resource, and only honored by GUI launchers: nobody waits on the
+/// exit code of a GUI application, but a console launcher has to forward it.
+fn detach() -> bool {
+    load_resource(RESOURCE_DETACH)
+        .ok()
+        .flatten()
+        .is_some_and(|data| data.first().is_some_and(|flag| *flag != 0))
+}
+
+/// Whether our standard input is a console, i.e., we were started from a terminal rather than
+/// from Explorer, which is the only place the "app starting" cursor is shown.
+fn stdin_is_console() -> bool {
+    let Ok(handle) = (unsafe { GetStdHandle(STD_INPUT_HANDLE) }) else {
+        return false;
+    };
+    let mut mode = CONSOLE_MODE::default();
+    !handle.is_invalid() && unsafe { GetConsoleMode(handle, &mut mode) }.is_ok()
+}
+
 pub fn bounce(is_gui: bool) -> ! {
-    let child_cmdline = make_child_cmdline();
+    // Launcher failures would otherwise go unnoticed, as GUI launchers have no console. Errors
+    // from the child itself are its own to report.
+    if is_gui {
+        diagnostics::enable_message_box();
+    }
+    let (trampoline_kind, python_path) =
+        read_trampoline_metadata().unwrap_or_else(|err| err.exit());
+    let child_cmdline = make_child_cmdline(trampoline_kind, python_path);
 
-    let mut si = STARTUPINFOA::default();
-    unsafe { GetStartupInfoA(&mut si) }
+    let detach = is_gui && detach();
 
-    let child_handle = spawn_child(&si, child_cmdline);
-    let job = Job::new().unwrap_or_else(|e| {
-        print_job_error_and_exit("uv trampoline failed to create job object", e);
-    });
+    let mut si = STARTUPINFOW::default();
+    unsafe { GetStartupInfoW(&mut si) }
+    inherit_std_handles(&si);
 
-    // Assign the child to the job object so it gets terminated if the trampoline is killed.
-    //
-    // If the assignment fails, the child may outlive the trampoline on forced kill, but normal
-    // execution (child exits naturally) is unaffected so we ignore the failure. This matches
-    // `distlib`'s approach where `AssignProcessToJobObject` failure is non-fatal [1]. There are
-    // various plausible scenarios where we may fail to assign the child, including simple race
-    // conditions like the child process exiting before assignment.
-    //
-    // See also <https://github.com/astral-sh/uv/pull/18170> which explores a more robust solution
-    // at the cost of increased complexity.
-    //
-    // [1]: https://github.com/pypa/distlib/blob/37df85a61ead2ea2dc48d0e06f7bfe2f209a982c/PC/launcher.c#L835
-    //
-    // SAFETY: child_handle is a valid process handle returned by spawn_child.
-    if let Err(e) = unsafe { job.assign_process(child_handle) } {
-        warn!(
-            "uv trampoline failed to assign child process to job object\n  Caused by: {} (os error {})",
-            e.message(),
-            e.code(),
-        );
-    }
+    // A detached child is meant to outlive us, so there's no job object to terminate it with.
+    let job = if detach {
+        None
+    } else {
+        // Without a job object, the child still runs, but isn't terminated if we're killed.
+        Job::new()
+            .inspect_err(|e| {
+                warn!(
+                    "uv trampoline failed to create job object; the child process will not be terminated if the trampoline is killed\n  Caused by: {} (os error {})",
+                    e.message(),
+                    e.code(),
+                );
+            })
+            .ok()
+    };
+
+    // Capture the working directory before spawning and pass it to the child explicitly, since
+    // we switch away from it below. `CreateProcessW` doesn't accept a working directory longer
+    // than `MAX_PATH`, in which case the child inherits ours as before.
+    let mut cwd = [0u16; MAX_PATH as usize];
+    // SAFETY: The call writes at most `cwd.len()` units, including the null terminator.
+    let cwd_len = unsafe { GetCurrentDirectoryW(Some(&mut cwd)) } as usize;
+    // On success, the length excludes the null terminator; otherwise, it's zero or the required
+    // size.
+    let cwd = (cwd_len > 0 && cwd_len < cwd.len()).then_some(&cwd[..=cwd_len]);
+
+    let child_process_info = spawn_child_in_job(&si, child_cmdline, cwd, job.as_ref())
+        .unwrap_or_else(|err| {
+            print_error_and_exit("uv trampoline failed to spawn Python child process", &err);
+        });
+
+    // Determine whether we were started from a terminal before we close our standard handles.
+    let from_console = is_gui && stdin_is_console();
+
+    let child_handle = child_process_info.hProcess;
+    resume_child(&child_process_info);
 
     // (best effort) Close all the handles that we can
     close_handles(&si);
 
     // (best effort) Switch to some innocuous directory, so we don't hold the original cwd open.
+    // The child was given its working directory explicitly at creation, so this can't affect it.
     // See distlib/PC/launcher.c::switch_working_directory
     if std::env::set_current_dir(std::env::temp_dir()).is_err() {
         warn!("Failed to set cwd to temp dir");
     }
 
+    // A detached launcher is done once the child has started; its exit code goes unreported.
+    if detach {
+        if !from_console {
+            clear_app_starting_state(child_handle);
+        }
+        exit_with_status(0);
+    }
+
     // We want to ignore control-C/control-Break/logout/etc.; the same event will
     // be delivered to the child, so we let them decide whether to exit or not.
     if let Err(e) = install_ctrl_handler() {
         print_ctrl_handler_error_and_exit(e);
     }
 
-    if is_gui {
+    // Explorer shows the "app starting" cursor for GUI applications that it launches; there's
+    // nothing to clear if we were started from a terminal.
+    if is_gui && !from_console {
         clear_app_starting_state(child_handle);
     }
 
-    let _ = unsafe { WaitForSingleObject(child_handle, INFINITE) };
+    // If the wait fails, the exit code would be `STILL_ACTIVE`, so bail out instead.
+    if unsafe { WaitForSingleObject(child_handle, INFINITE) } == WAIT_FAILED {
+        print_last_error_and_exit("uv trampoline failed to wait for child process");
+    }
     let mut exit_code = 0u32;
     if unsafe { GetExitCodeProcess(child_handle, &mut exit_code) }.is_err() {
         print_last_error_and_exit("uv trampoline failed to get exit code of child process");
@@ -506,9 +646,31 @@ fn error_and_exit(message: &str) -> ! {
     exit_with_status(1);
 }
 
+#[cold]
+fn missing_python_and_exit(python_exe: &Path) -> ! {
+    error!(
+        "uv trampoline failed to find Python interpreter at `{}`\n  hint: The environment may have been moved or deleted; recreate it with `uv venv`, or reinstall Python with `uv python install`",
+        &*python_exe.to_string_lossy()
+    );
+    exit_with_status(1);
+}
+
+#[cold]
+fn command_line_too_long_and_exit(length: usize) -> ! {
+    error!(
+        "uv trampoline failed to launch Python: the command line is {} characters long, exceeding the Windows limit of {} characters",
+        length, MAX_COMMAND_LINE_LENGTH
+    );
+    exit_with_status(1);
+}
+
 #[cold]
 fn print_last_error_and_exit(message: &str) -> ! {
-    let err = std::io::Error::last_os_error();
+    print_error_and_exit(message, &std::io::Error::last_os_error());
+}
+
+#[cold]
+fn print_error_and_exit(message: &str, err: &std::io::Error) -> ! {
     let err_no_str = err
         .raw_os_error()
         .map(|raw_error| format!(" (os error {})", raw_error))
@@ -524,9 +686,35 @@ fn print_last_error_and_exit(message: &str) -> ! {
     exit_with_status(1);
 }
 
+/// Exit with the given code.
+///
+/// The code is passed to `ExitProcess` unchanged, so that, e.g., an `NTSTATUS` from a crashed
+/// child like `0xC0000005` is propagated as-is.
+///
+/// This includes `STATUS_CONTROL_C_EXIT` from a child that was interrupted. It isn't mapped to a
+/// POSIX-style `130`: it's the code `python.exe` itself exits with on Ctrl+C or Ctrl+Break, and
+/// the one that `cmd.exe` and PowerShell recognize as an interrupt.
 #[cold]
 fn exit_with_status(code: u32) -> ! {
-    // ~5-10kb
-    // Pulls in core::fmt::{write, Write, getcount}
-    std::process::exit(code as _)
+    // SAFETY: `ExitProcess` has no preconditions; it never returns.
+    unsafe { ExitProcess(code) }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{TrampolineError, decode_python_path};
+
+    #[test]
+    fn python_path() {
+        assert_eq!(
+            decode_python_path(b"C:\\Python\\python.exe".to_vec()).ok(),
+            Some(PathBuf::from("C:\\Python\\python.exe"))
+        );
+        assert!(matches!(
+            decode_python_path(vec![0xff]),
+            Err(TrampolineError::InvalidUtf8)
+        ));
+    }
 }
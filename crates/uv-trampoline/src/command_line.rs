@@ -0,0 +1,334 @@
+//! Splitting the trampoline's own command line, as returned by `GetCommandLineW`, and quoting the
+//! child's.
+//!
+//! Unlike [`crate::bounce`], this module is available on all platforms.
+
+const BACKSLASH: u16 = b'\\' as u16;
+const QUOTE: u16 = b'"' as u16;
+const SPACE: u16 = b' ' as u16;
+const TAB: u16 = b'\t' as u16;
+
+/// The maximum length of a command line passed to `CreateProcessW`, in UTF-16 code units,
+/// including the terminating null.
+pub const MAX_COMMAND_LINE_LENGTH: usize = 32_767;
+
+/// Skip over the program name at the start of a UTF-16 command line, returning the remainder,
+/// including the whitespace that separates it from the program name.
+///
+/// The program name follows the [Microsoft C runtime rules] for `argv[0]`, which differ from
+/// those for the remaining arguments: double quotes toggle whether spaces and tabs end the
+/// argument, and backslashes are always literal, since the program name is a path. In particular,
+/// `"C:\dir\" arg` is the program name `C:\dir\` followed by `arg`. A command line that starts with
+/// whitespace has an empty program name.
+///
+/// [Microsoft C runtime rules]: https://learn.microsoft.com/en-us/cpp/c-language/parsing-c-command-line-arguments
+pub fn skip_one_argument(arguments: &[u16]) -> &[u16] {
+    let mut quoted = false;
+    let end = arguments
+        .iter()
+        .position(|&unit| {
+            if unit == QUOTE {
+                quoted = !quoted;
+            }
+            !quoted && matches!(unit, SPACE | TAB)
+        })
+        .unwrap_or(arguments.len());
+    &arguments[end..]
+}
+
+/// Append a program name, quoted such that it's parsed back verbatim as `argv[0]`, e.g., by
+/// [`skip_one_argument`].
+///
+/// Backslashes are never escapes in the program name, so they, including the `\\?\` prefix, are
+/// kept as-is. The program name can't contain double quotes, since they can't be escaped, but
+/// neither can Windows paths.
+pub fn push_quoted_program(program: impl IntoIterator<Item = u16>, command: &mut Vec<u16>) {
+    command.push(QUOTE);
+    command.extend(program);
+    command.push(QUOTE);
+}
+
+/// Append an argument other than the program name, quoted such that `CommandLineToArgvW` and the
+/// Microsoft C runtime parse it back verbatim.
+///
+/// Arguments without spaces, tabs, or double quotes are appended as-is. Otherwise, the argument is
+/// wrapped in double quotes, and backslashes follow the 2N/2N+1 rules: a run of N backslashes is
+/// doubled if it precedes a double quote, including the closing one, and each double quote in the
+/// argument is preceded by one more backslash. Other backslashes are literal.
+pub fn push_quoted_argument<I>(argument: I, command: &mut Vec<u16>)
+where
+    I: IntoIterator<Item = u16>,
+    I::IntoIter: Clone,
+{
+    let argument = argument.into_iter();
+    let mut units = argument.clone().peekable();
+    if units.peek().is_some() && !units.any(|unit| matches!(unit, SPACE | TAB | QUOTE)) {
+        command.extend(argument);
+        return;
+    }
+
+    command.push(QUOTE);
+    let mut backslashes = 0;
+    for unit in argument {
+        if unit == BACKSLASH {
+            backslashes += 1;
+        } else {
+            if unit == QUOTE {
+                // Escape the preceding backslashes and the quote itself.
+                command.extend(std::iter::repeat_n(BACKSLASH, backslashes + 1));
+            }
+            backslashes = 0;
+        }
+        command.push(unit);
+    }
+    // Escape trailing backslashes, so that they don't escape the closing quote.
+    command.extend(std::iter::repeat_n(BACKSLASH, backslashes));
+    command.push(QUOTE);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BACKSLASH, QUOTE, SPACE, TAB, push_quoted_argument, push_quoted_program, skip_one_argument,
+    };
+
+    /// Return the remainder of `command_line` after the program name.
+    fn skip(command_line: &str) -> String {
+        let wide = command_line.encode_utf16().collect::<Vec<_>>();
+        String::from_utf16(skip_one_argument(&wide)).unwrap()
+    }
+
+    #[test]
+    fn program_name() {
+        for (command_line, remainder) in [
+            // Bare program names.
+            ("", ""),
+            ("black.exe", ""),
+            ("black.exe --fast", " --fast"),
+            ("black.exe\t--fast", "\t--fast"),
+            ("black.exe  --fast  ", "  --fast  "),
+            (r"C:\venv\Scripts\black.exe --fast", " --fast"),
+            // Leading whitespace yields an empty program name.
+            (" black.exe --fast", " black.exe --fast"),
+            ("\tblack.exe", "\tblack.exe"),
+            // Only spaces and tabs separate arguments.
+            ("black.exe\n--fast", ""),
+            ("black.exe\r\n--fast", ""),
+            // Fully-quoted program names with embedded spaces.
+            (r#""C:\Program Files\black.exe" --fast"#, " --fast"),
+            (r#""C:\Program Files\black.exe""#, ""),
+            ("\"C:\\Program Files\\black.exe\"\t--fast", "\t--fast"),
+            (r#""" --fast"#, " --fast"),
+            // Partially-quoted program names.
+            (r#"C:\Program" "Files\black.exe --fast"#, " --fast"),
+            (r#""C:\Program Files"\black.exe --fast"#, " --fast"),
+            (r#""C:\odd ""name""\black.exe" --fast"#, " --fast"),
+            // An unterminated quote extends to the end of the command line.
+            (r#""C:\Program Files\black.exe --fast"#, ""),
+            // Backslashes are literal, including before a closing quote.
+            (r#""C:\venv\" --fast"#, " --fast"),
+            (r#""C:\venv\\" --fast"#, " --fast"),
+            (r#""C:\venv\\\" --fast"#, " --fast"),
+            (r"a\\\\b.exe --fast", " --fast"),
+            (r#"a\"b c" --fast"#, " --fast"),
+            // UNC paths.
+            (r"\\server\share\black.exe --fast", " --fast"),
+            (r#""\\server\share\my venv\black.exe" --fast"#, " --fast"),
+            (r"\\?\C:\venv\black.exe --fast", " --fast"),
+            // The remaining arguments are returned verbatim.
+            (
+                r#"black.exe "foo bar" baz\" "qux\\" \\\"x"#,
+                r#" "foo bar" baz\" "qux\\" \\\"x"#,
+            ),
+            // Non-ASCII program names.
+            (r#""C:\données\black.exe" –fast"#, " –fast"),
+        ] {
+            assert_eq!(skip(command_line), remainder, "{command_line:?}");
+        }
+    }
+
+    #[test]
+    fn quoted_arguments() {
+        for (argument, quoted) in [
+            ("", r#""""#),
+            ("--fast", "--fast"),
+            (r"C:\venv\black.py", r"C:\venv\black.py"),
+            (r"C:\my venv\black.py", r#""C:\my venv\black.py""#),
+            ("a\tb", "\"a\tb\""),
+            // Backslashes before a quote are doubled, and the quote is escaped.
+            (r#"say "hi""#, r#""say \"hi\"""#),
+            (r#"a\"b"#, r#""a\\\"b""#),
+            // Trailing backslashes are doubled, so that they don't escape the closing quote.
+            (r"C:\my venv\", r#""C:\my venv\\""#),
+            (r"C:\my venv\\", r#""C:\my venv\\\\""#),
+            // Backslashes elsewhere are literal.
+            (r"\\server\my share\a\\b", r#""\\server\my share\a\\b""#),
+        ] {
+            let mut command = Vec::new();
+            push_quoted_argument(argument.encode_utf16(), &mut command);
+            assert_eq!(
+                String::from_utf16(&command).unwrap(),
+                quoted,
+                "{argument:?}"
+            );
+        }
+    }
+
+    /// A reimplementation of the Microsoft C runtime's command line parsing, written for clarity
+    /// rather than speed, returning `argv`.
+    ///
+    /// See: <https://learn.microsoft.com/en-us/cpp/c-language/parsing-c-command-line-arguments>
+    fn reference_argv(command_line: &[u16]) -> Vec<Vec<u16>> {
+        let mut argv = Vec::new();
+        let mut position = 0;
+
+        // The program name: quotes toggle quoting and are removed, and backslashes are literal.
+        let mut program = Vec::new();
+        let mut quoted = false;
+        while let Some(&unit) = command_line.get(position) {
+            if unit == QUOTE {
+                quoted = !quoted;
+            } else if !quoted && matches!(unit, SPACE | TAB) {
+                break;
+            } else {
+                program.push(unit);
+            }
+            position += 1;
+        }
+        argv.push(program);
+
+        // The remaining arguments.
+        loop {
+            while matches!(command_line.get(position), Some(&(SPACE | TAB))) {
+                position += 1;
+            }
+            if position == command_line.len() {
+                return argv;
+            }
+
+            let mut argument = Vec::new();
+            let mut quoted = false;
+            while let Some(&unit) = command_line.get(position) {
+                if unit == BACKSLASH {
+                    let backslashes = command_line[position..]
+                        .iter()
+                        .take_while(|&&unit| unit == BACKSLASH)
+                        .count();
+                    position += backslashes;
+                    if command_line.get(position) == Some(&QUOTE) {
+                        // 2N backslashes and a quote yield N backslashes and a delimiter, while
+                        // 2N+1 backslashes and a quote yield N backslashes and a literal quote.
+                        argument.extend(std::iter::repeat_n(BACKSLASH, backslashes / 2));
+                        if backslashes % 2 == 1 {
+                            argument.push(QUOTE);
+                            position += 1;
+                        }
+                    } else {
+                        argument.extend(std::iter::repeat_n(BACKSLASH, backslashes));
+                    }
+                    continue;
+                }
+                if unit == QUOTE {
+                    if quoted && command_line.get(position + 1) == Some(&QUOTE) {
+                        // A doubled quote within a quoted span is a literal quote.
+                        argument.push(QUOTE);
+                        position += 2;
+                    } else {
+                        quoted = !quoted;
+                        position += 1;
+                    }
+                    continue;
+                }
+                if !quoted && matches!(unit, SPACE | TAB) {
+                    break;
+                }
+                argument.push(unit);
+                position += 1;
+            }
+            argv.push(argument);
+        }
+    }
+
+    /// A xorshift generator, such that the property tests are reproducible without dependencies.
+    struct Random(u64);
+
+    impl Random {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        /// A string of up to 8 units drawn from `alphabet`.
+        fn string(&mut self, alphabet: &[u16]) -> Vec<u16> {
+            let len = self.next() % 9;
+            (0..len)
+                .map(|_| alphabet[(self.next() % alphabet.len() as u64) as usize])
+                .collect()
+        }
+    }
+
+    /// Units that are significant to the quoting rules, plus ordinary and non-ASCII characters.
+    const ALPHABET: [u16; 6] = [BACKSLASH, QUOTE, SPACE, TAB, b'a' as u16, 0xe9];
+
+    #[test]
+    fn program_name_matches_reference() {
+        let mut random = Random(0x2545_f491_4f6c_dd1d);
+        for _ in 0..10_000 {
+            let command_line = random.string(&ALPHABET);
+            let remainder = skip_one_argument(&command_line);
+            let program = &command_line[..command_line.len() - remainder.len()];
+            let expected = reference_argv(&command_line);
+            // Quotes are removed from the program name, and backslashes are kept as-is.
+            assert_eq!(
+                program
+                    .iter()
+                    .copied()
+                    .filter(|&unit| unit != QUOTE)
+                    .collect::<Vec<_>>(),
+                expected[0]
+            );
+            // The remainder parses to the same arguments as the full command line.
+            assert_eq!(
+                reference_argv(&[program, remainder].concat())[1..],
+                expected[1..],
+                "{:?}",
+                String::from_utf16_lossy(&command_line)
+            );
+        }
+    }
+
+    #[test]
+    fn quoting_round_trips() {
+        let mut random = Random(0x9e37_79b9_7f4a_7c15);
+        let program_alphabet = [BACKSLASH, SPACE, TAB, b'a' as u16, 0xe9];
+        for _ in 0..10_000 {
+            let program = random.string(&program_alphabet);
+            let arguments = (0..random.next() % 4)
+                .map(|_| random.string(&ALPHABET))
+                .collect::<Vec<_>>();
+
+            let mut command_line = Vec::new();
+            push_quoted_program(program.iter().copied(), &mut command_line);
+            for argument in &arguments {
+                command_line.push(SPACE);
+                push_quoted_argument(argument.iter().copied(), &mut command_line);
+            }
+
+            let mut expected = vec![program.clone()];
+            expected.extend(arguments.iter().cloned());
+            assert_eq!(
+                reference_argv(&command_line),
+                expected,
+                "{:?}",
+                String::from_utf16_lossy(&command_line)
+            );
+            // The quoted program name is skipped as a whole.
+            assert_eq!(
+                skip_one_argument(&command_line).len(),
+                command_line.len() - program.len() - 2
+            );
+        }
+    }
+}
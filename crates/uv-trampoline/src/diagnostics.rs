@@ -1,12 +1,13 @@
 use std::convert::Infallible;
-use std::ffi::CString;
 use std::io::Write;
 use std::os::windows::io::AsRawHandle;
 use std::string::String;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::vec::Vec;
 
 use ufmt_write::uWrite;
-use windows::Win32::UI::WindowsAndMessaging::{MESSAGEBOX_STYLE, MessageBoxA};
-use windows::core::PCSTR;
+use windows::Win32::UI::WindowsAndMessaging::{MESSAGEBOX_STYLE, MessageBoxW};
+use windows::core::PCWSTR;
 
 #[macro_export]
 macro_rules! error {
@@ -43,18 +44,32 @@ impl uWrite for StringBuffer {
     }
 }
 
+/// Whether errors are shown in a message box rather than written to stderr.
+static MESSAGE_BOX: AtomicBool = AtomicBool::new(false);
+
+/// Show errors in a message box, since GUI launchers have no console to write them to.
+pub(crate) fn enable_message_box() {
+    MESSAGE_BOX.store(true, Ordering::Relaxed);
+}
+
 #[cold]
 pub(crate) fn write_diagnostic(message: &str, is_error: bool) {
     let prefix = if is_error { "error" } else { "warning" };
     let mut stderr = std::io::stderr();
-    if !stderr.as_raw_handle().is_null() {
+    let has_stderr = !stderr.as_raw_handle().is_null();
+    if is_error && (MESSAGE_BOX.load(Ordering::Relaxed) || !has_stderr) {
+        let error = format!("{}: {}", prefix, message);
+        // Use the wide API, so that paths outside the active code page are displayed correctly.
+        let nul_terminated = error
+            .encode_utf16()
+            .chain(std::iter::once(0))
+            .collect::<Vec<u16>>();
+        let pcwstr_message = PCWSTR::from_raw(nul_terminated.as_ptr());
+        // SAFETY: The message is null-terminated and outlives the call.
+        unsafe { MessageBoxW(None, pcwstr_message, None, MESSAGEBOX_STYLE(0)) };
+    } else if has_stderr {
         let _ = stderr.write_all(prefix.as_bytes());
         let _ = stderr.write_all(b": ");
         let _ = stderr.write_all(message.as_bytes());
-    } else if is_error {
-        let error = format!("{}: {}", prefix, message);
-        let nul_terminated = unsafe { CString::new(error).unwrap_unchecked() };
-        let pcstr_message = PCSTR::from_raw(nul_terminated.as_ptr() as *const _);
-        unsafe { MessageBoxA(None, pcstr_message, None, MESSAGEBOX_STYLE(0)) };
     }
 }
@@ -0,0 +1,87 @@
+//! Deciding which environment variables a Python launcher sets for its child.
+//!
+//! Unlike [`crate::bounce`], this module is available on all platforms.
+
+use std::ffi::OsStr;
+use std::path::Path;
+
+/// Whether to keep an inherited `__PYVENV_LAUNCHER__`, rather than pointing it at this launcher.
+///
+/// An outer launcher, e.g., the `py` launcher or another trampoline, sets `__PYVENV_LAUNCHER__` to
+/// the executable the user invoked, which is what Python should report as `sys.executable`. The
+/// inherited value is kept if it's non-empty and points at an existing file, so a value left
+/// behind by a launcher that has since been removed is replaced.
+pub fn keep_pyvenv_launcher(inherited: Option<&OsStr>) -> bool {
+    inherited.is_some_and(|launcher| !launcher.is_empty() && Path::new(launcher).is_file())
+}
+
+/// Whether to set `PYTHONHOME` for an interpreter outside a virtual environment, given the
+/// inherited `PYTHONHOME` and the value uv recorded in `UV_INTERNAL__PYTHONHOME` when it last set
+/// `PYTHONHOME`.
+///
+/// `PYTHONHOME` is only set if it's unset, or if it still holds the value uv set for a parent
+/// process. Any other value is the user's, including an empty one, and is left alone.
+pub fn should_set_python_home(python_home: Option<&OsStr>, marker: Option<&OsStr>) -> bool {
+    match (python_home, marker) {
+        (None, _) => true,
+        (Some(home), Some(marker)) => home == marker,
+        (Some(_), None) => false,
+    }
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::disallowed_methods,
+    reason = "uv-trampoline doesn't depend on `fs_err`"
+)]
+mod tests {
+    use std::ffi::OsStr;
+
+    use super::{keep_pyvenv_launcher, should_set_python_home};
+
+    #[test]
+    fn pyvenv_launcher() {
+        let directory = std::env::temp_dir().join(format!(
+            "uv-trampoline-pyvenv-launcher-{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&directory).unwrap();
+        let launcher = directory.join("python.exe");
+        std::fs::write(&launcher, b"").unwrap();
+
+        // Unset and empty values are replaced.
+        assert!(!keep_pyvenv_launcher(None));
+        assert!(!keep_pyvenv_launcher(Some(OsStr::new(""))));
+
+        // A value set by an outer launcher is kept.
+        assert!(keep_pyvenv_launcher(Some(launcher.as_os_str())));
+
+        // A value pointing at a missing file, or at a directory, is replaced.
+        assert!(!keep_pyvenv_launcher(Some(
+            directory.join("missing.exe").as_os_str()
+        )));
+        assert!(!keep_pyvenv_launcher(Some(directory.as_os_str())));
+
+        let _ = std::fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn python_home() {
+        let home = OsStr::new(r"C:\Python312");
+        let other = OsStr::new(r"C:\Python313");
+        let empty = OsStr::new("");
+
+        // Unset, so it's set.
+        assert!(should_set_python_home(None, None));
+        assert!(should_set_python_home(None, Some(home)));
+
+        // Set by uv for a parent process, so it's replaced.
+        assert!(should_set_python_home(Some(home), Some(home)));
+
+        // Set by the user, so it's kept, even if empty.
+        assert!(!should_set_python_home(Some(home), None));
+        assert!(!should_set_python_home(Some(empty), None));
+        assert!(!should_set_python_home(Some(other), Some(home)));
+        assert!(!should_set_python_home(Some(empty), Some(home)));
+    }
+}
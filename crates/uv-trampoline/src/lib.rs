@@ -1,2 +1,22 @@
+#[cfg(windows)]
 pub mod bounce;
+pub mod command_line;
+#[cfg(windows)]
 mod diagnostics;
+pub mod environment;
+pub mod metadata;
+pub mod venv;
+
+/// Trampolines can only be launched on Windows; this stub lets the crate build on other hosts.
+#[cfg(not(windows))]
+pub mod bounce {
+    use std::io::Write;
+
+    pub fn bounce(_is_gui: bool) -> ! {
+        let _ = writeln!(
+            std::io::stderr(),
+            "uv trampolines are only supported on Windows"
+        );
+        std::process::exit(1)
+    }
+}
@@ -0,0 +1,80 @@
+//! The trampoline metadata format, shared by the launcher and any tooling that constructs
+//! launchers.
+//!
+//! Unlike [`crate::bounce`], this module is available on all platforms.
+
+/// The newest metadata format version this trampoline understands.
+///
+/// Launchers without a version resource predate versioning and are always supported.
+pub const TRAMPOLINE_VERSION: u8 = 1;
+
+/// The kind of trampoline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrampolineKind {
+    /// The trampoline should execute itself, it's a zipped Python script.
+    Script,
+    /// The trampoline should just execute Python, it's a proxy Python executable.
+    Python,
+}
+
+impl TrampolineKind {
+    /// Parse the kind from the contents of the `UV_TRAMPOLINE_KIND` resource.
+    pub fn from_resource(data: &[u8]) -> Option<Self> {
+        match data.first() {
+            Some(1) => Some(Self::Script),
+            Some(2) => Some(Self::Python),
+            _ => None,
+        }
+    }
+
+    /// The contents of the `UV_TRAMPOLINE_KIND` resource for this kind.
+    pub fn to_resource(self) -> [u8; 1] {
+        match self {
+            Self::Script => [1],
+            Self::Python => [2],
+        }
+    }
+}
+
+/// Whether the format version resource holds a version this trampoline understands.
+pub fn is_supported_version(data: &[u8]) -> bool {
+    matches!(data, [version] if (1..=TRAMPOLINE_VERSION).contains(version))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TrampolineKind, is_supported_version};
+
+    #[test]
+    fn trampoline_kind() {
+        assert_eq!(
+            TrampolineKind::from_resource(&[1]),
+            Some(TrampolineKind::Script)
+        );
+        assert_eq!(
+            TrampolineKind::from_resource(&[2]),
+            Some(TrampolineKind::Python)
+        );
+        assert!(TrampolineKind::from_resource(&[3]).is_none());
+        assert!(TrampolineKind::from_resource(&[]).is_none());
+    }
+
+    #[test]
+    fn trampoline_kind_round_trip() {
+        for kind in [TrampolineKind::Script, TrampolineKind::Python] {
+            assert_eq!(
+                TrampolineKind::from_resource(&kind.to_resource()),
+                Some(kind)
+            );
+        }
+    }
+
+    #[test]
+    fn version() {
+        assert!(is_supported_version(&[1]));
+        assert!(!is_supported_version(&[0]));
+        assert!(!is_supported_version(&[2]));
+        assert!(!is_supported_version(&[1, 0]));
+        assert!(!is_supported_version(&[]));
+    }
+}
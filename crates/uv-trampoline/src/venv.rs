@@ -0,0 +1,93 @@
+//! Virtual environment detection, following [PEP 405].
+//!
+//! Unlike [`crate::bounce`], this module is available on all platforms.
+//!
+//! [PEP 405]: https://peps.python.org/pep-0405/
+
+use std::path::Path;
+
+/// Whether the given Python executable belongs to a virtual environment.
+///
+/// Per PEP 405, the executable belongs to a virtual environment if there's a `pyvenv.cfg` file
+/// next to it, or one directory above it. The executable is not dereferenced. PEP 405 also
+/// requires the file to have a `home` key, but in practice we have found checking for it to be
+/// unnecessary, and parsing the file costs several kilobytes of launcher size.
+pub fn is_virtualenv(executable: &Path) -> bool {
+    let Some(parent) = executable.parent() else {
+        return false;
+    };
+    [Some(parent), parent.parent()]
+        .into_iter()
+        .flatten()
+        .any(|directory| directory.join("pyvenv.cfg").is_file())
+}
+
+#[cfg(test)]
+#[expect(
+    clippy::disallowed_methods,
+    reason = "uv-trampoline doesn't depend on `fs_err`"
+)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use super::is_virtualenv;
+
+    /// A directory under the system temporary directory, removed on drop.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path =
+                std::env::temp_dir().join(format!("uv-trampoline-{name}-{}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&path);
+            std::fs::create_dir_all(&path).unwrap();
+            Self(path)
+        }
+
+        fn path(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn grandparent_layout() {
+        // `<venv>\Scripts\python.exe`, as created by `venv` on Windows.
+        let venv = TempDir::new("grandparent-layout");
+        std::fs::create_dir_all(venv.path().join("Scripts")).unwrap();
+        std::fs::write(venv.path().join("pyvenv.cfg"), "home = C:\\Python312\r\n").unwrap();
+
+        let python = venv.path().join("Scripts").join("python.exe");
+        assert!(is_virtualenv(&python));
+    }
+
+    #[test]
+    fn parent_layout() {
+        // `<venv>\python.exe`, with the interpreter next to `pyvenv.cfg`.
+        let venv = TempDir::new("parent-layout");
+        std::fs::write(venv.path().join("pyvenv.cfg"), "home = C:\\Python312\n").unwrap();
+
+        let python = venv.path().join("python.exe");
+        assert!(is_virtualenv(&python));
+    }
+
+    #[test]
+    fn not_a_venv() {
+        // No `pyvenv.cfg` at all.
+        let install = TempDir::new("not-a-venv");
+        let python = install.path().join("python.exe");
+        assert!(!is_virtualenv(&python));
+
+        // A `pyvenv.cfg` that's a directory.
+        std::fs::create_dir(install.path().join("pyvenv.cfg")).unwrap();
+        assert!(!is_virtualenv(&python));
+
+        // A bare file name has no parent to search.
+        assert!(!is_virtualenv(Path::new("")));
+    }
+}
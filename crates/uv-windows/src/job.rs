@@ -75,7 +75,8 @@ impl Job {
     ///
     /// The job is configured with:
     /// - `JOB_OBJECT_LIMIT_KILL_ON_JOB_CLOSE`: Terminate all processes when job handle closes
-    /// - `JOB_OBJECT_LIMIT_SILENT_BREAKAWAY_OK`: Allow child processes to break away if needed
+    /// - `JOB_OBJECT_LIMIT_SILENT_BREAKAWAY_OK`: Don't add processes spawned by assigned processes
+    ///   to the job, so that only the assigned processes themselves are terminated
     #[allow(unsafe_code)]
     pub fn new() -> Result<Self, JobError> {
         // SAFETY: CreateJobObjectW with None parameters creates an unnamed job object.
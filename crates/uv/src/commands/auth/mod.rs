@@ -2,4 +2,5 @@ pub(crate) mod dir;
 pub(crate) mod helper;
 pub(crate) mod login;
 pub(crate) mod logout;
+pub(crate) mod save;
 pub(crate) mod token;
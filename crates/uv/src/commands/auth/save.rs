@@ -0,0 +1,120 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use uv_auth::{Credentials, KeyringProvider, Service, TextCredentialStore};
+use uv_cli::SaveCredentials;
+use uv_distribution_types::{Index, IndexLocations};
+use uv_preview::{Preview, PreviewFeature};
+use uv_static::EnvVars;
+use uv_warnings::warn_user;
+
+use crate::printer::Printer;
+
+/// Persist the credentials provided for the given indexes, such that subsequent invocations can
+/// authenticate without them.
+pub(crate) async fn save(
+    index_locations: &IndexLocations,
+    target: SaveCredentials,
+    printer: Printer,
+    preview: Preview,
+) -> Result<()> {
+    let credentials = index_locations
+        .allowed_indexes()
+        .into_iter()
+        .filter_map(|index| {
+            let credentials = index.credentials()?;
+            if credentials.username().is_none() || credentials.password().is_none() {
+                return None;
+            }
+            Some((index, credentials))
+        })
+        .collect::<Vec<_>>();
+
+    if credentials.is_empty() {
+        warn_user!("No index credentials were provided; nothing to save for `--save-credentials`");
+        return Ok(());
+    }
+
+    match target {
+        SaveCredentials::Keyring => {
+            if !preview.is_enabled(PreviewFeature::NativeAuth) {
+                warn_user!(
+                    "Credentials in the system keyring are only used when the `native-auth` preview feature is enabled"
+                );
+            }
+            let provider = KeyringProvider::native();
+            for (index, credentials) in &credentials {
+                let service = service(index)?;
+                provider.store(service.url(), credentials).await?;
+                writeln!(
+                    printer.stderr(),
+                    "Stored credentials for {} in the system keyring",
+                    display_service(&service, credentials).bold().cyan()
+                )?;
+            }
+        }
+        SaveCredentials::File => {
+            let path = TextCredentialStore::default_file()?;
+            let (mut store, lock) = TextCredentialStore::read_or_default(&path).await?;
+            let mut stored = Vec::with_capacity(credentials.len());
+            for (index, credentials) in credentials {
+                let service = service(index)?;
+                stored.push(display_service(&service, &credentials));
+                store.insert(service, credentials);
+            }
+            store.write(&path, lock)?;
+            for display_url in stored {
+                writeln!(
+                    printer.stderr(),
+                    "Stored credentials for {}",
+                    display_url.bold().cyan()
+                )?;
+            }
+        }
+        SaveCredentials::Env => {
+            for (index, credentials) in &credentials {
+                let Some(name) = index.name.as_ref() else {
+                    warn_user!(
+                        "Credentials for `{}` can only be provided via the environment for named indexes; use `--index <name>=<url>` or `--save-credentials file` instead",
+                        index.raw_url().without_credentials()
+                    );
+                    continue;
+                };
+                let name = name.to_env_var();
+                if let Some(username) = credentials.username() {
+                    writeln!(
+                        printer.stdout(),
+                        "{}={username}",
+                        EnvVars::index_username(&name)
+                    )?;
+                }
+                if let Some(password) = credentials.password() {
+                    writeln!(
+                        printer.stdout(),
+                        "{}={password}",
+                        EnvVars::index_password(&name)
+                    )?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Return the [`Service`] to store credentials for an [`Index`] under, i.e., the index's root URL
+/// without any embedded credentials.
+fn service(index: &Index) -> Result<Service> {
+    let mut url = index.root_url().unwrap_or_else(|| index.raw_url().clone());
+    url.remove_credentials();
+    Ok(Service::try_from(url)?)
+}
+
+fn display_service(service: &Service, credentials: &Credentials) -> String {
+    match credentials.username() {
+        Some(username) => format!("{username}@{}", service.url()),
+        None => service.url().to_string(),
+    }
+}
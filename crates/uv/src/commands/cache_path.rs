@@ -0,0 +1,39 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use owo_colors::OwoColorize;
+
+use uv_cache::{Cache, CacheBucket};
+use uv_cli::CacheCategory;
+use uv_fs::Simplified;
+
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Show the path to a cache subdirectory.
+pub(crate) async fn cache_path(
+    cache: Cache,
+    category: CacheCategory,
+    create: bool,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let bucket = match category {
+        CacheCategory::Wheels => CacheBucket::Wheels,
+        CacheCategory::Sdists => CacheBucket::SourceDistributions,
+        CacheCategory::Simple => CacheBucket::Simple,
+        CacheCategory::Git => CacheBucket::Git,
+        CacheCategory::Interpreter => CacheBucket::Interpreter,
+    };
+
+    let path = if create {
+        let cache = cache.init().await?;
+        let path = cache.bucket(bucket);
+        fs_err::create_dir_all(&path)?;
+        path
+    } else {
+        cache.bucket(bucket)
+    };
+
+    writeln!(printer.stdout(), "{}", path.simplified_display().cyan())?;
+    Ok(ExitStatus::Success)
+}
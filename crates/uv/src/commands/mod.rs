@@ -13,10 +13,12 @@ pub(crate) use auth::dir::dir as auth_dir;
 pub(crate) use auth::helper::helper as auth_helper;
 pub(crate) use auth::login::login as auth_login;
 pub(crate) use auth::logout::logout as auth_logout;
+pub(crate) use auth::save::save as auth_save;
 pub(crate) use auth::token::token as auth_token;
 pub(crate) use build_frontend::build_frontend;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
+pub(crate) use cache_path::cache_path;
 pub(crate) use cache_prune::cache_prune;
 pub(crate) use cache_size::cache_size;
 pub(crate) use help::help;
@@ -80,6 +82,7 @@ pub(crate) mod build_backend;
 mod build_frontend;
 mod cache_clean;
 mod cache_dir;
+mod cache_path;
 mod cache_prune;
 mod cache_size;
 mod diagnostics;
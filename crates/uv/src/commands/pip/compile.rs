@@ -1,34 +1,43 @@
-use std::collections::BTreeSet;
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet};
 use std::env;
 use std::ffi::OsStr;
-use std::io::Write;
+use std::fmt::Write as _;
+use std::io::Write as _;
 use std::path::Path;
 use std::str::FromStr;
+use std::sync::Arc;
 
 use anyhow::{Result, anyhow};
+use futures::{StreamExt, TryStreamExt};
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashSet;
 use tracing::debug;
 
-use uv_cache::Cache;
-use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
+use uv_cache::{Cache, Refresh};
+use uv_client::{
+    BaseClientBuilder, FlatIndexClient, MetadataFormat, OwnedArchive, RegistryClientBuilder,
+};
 use uv_configuration::{
     BuildIsolation, BuildOptions, Concurrency, Constraints, ExtrasSpecification, IndexStrategy,
     NoBinary, NoBuild, NoSources, PipCompileFormat, Reinstall, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
-use uv_distribution::LoweredExtraBuildDependencies;
+use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies};
 use uv_distribution_types::{
-    ConfigSettings, DependencyMetadata, ExtraBuildVariables, HashGeneration, Index, IndexLocations,
-    NameRequirementSpecification, Origin, PackageConfigSettings, Requirement, RequiresPython,
-    UnresolvedRequirementSpecification, Verbatim,
+    ConfigSettings, DependencyMetadata, ExtraBuildVariables, HashGeneration, Index,
+    IndexCapabilities, IndexLocations, IndexMetadataRef, IndexUrl, NameRequirementSpecification,
+    Origin, PackageConfigSettings, Requirement, RequiresPython, ResolutionDiagnostic, ResolvedDist,
+    UnresolvedRequirement, UnresolvedRequirementSpecification, Verbatim,
 };
 use uv_fs::{CWD, Simplified};
 use uv_git::ResolvedRepositoryReference;
 use uv_install_wheel::LinkMode;
-use uv_normalize::PackageName;
+use uv_normalize::{ExtraName, PackageName};
+use uv_pep440::Version;
+use uv_platform_tags::Tags;
 use uv_preview::Preview;
 use uv_pypi_types::{Conflicts, SupportedEnvironments};
 use uv_python::{
@@ -37,13 +46,14 @@ use uv_python::{
 };
 use uv_requirements::upgrade::{LockedRequirements, read_pylock_toml_requirements};
 use uv_requirements::{
-    GroupsSpecification, RequirementsSource, RequirementsSpecification, is_pylock_toml,
-    upgrade::read_requirements_txt,
+    GroupsSpecification, RequirementsSource, RequirementsSpecification, SourceTreeResolver,
+    is_pylock_toml, upgrade::read_requirements_txt,
 };
 use uv_resolver::{
-    AnnotationStyle, DependencyMode, DisplayResolutionGraph, ExcludeNewer, FlatIndex, ForkStrategy,
-    InMemoryIndex, OptionsBuilder, PrereleaseMode, PylockToml, PythonRequirement, ResolutionMode,
-    ResolverEnvironment,
+    AnnotationStyle, DependencyMode, DisplayResolutionConstraints, DisplayResolutionGraph,
+    DisplayResolutionTree, ExcludeNewer, FlatIndex, ForkStrategy, InMemoryIndex, OptionsBuilder,
+    PrereleaseMode, PylockToml, PythonRequirement, ResolutionMode, ResolverEnvironment,
+    ResolverOutput, WheelTags,
 };
 use uv_settings::PythonInstallMirrors;
 use uv_static::EnvVars;
@@ -55,7 +65,7 @@ use uv_workspace::pyproject::ExtraBuildDependencies;
 
 use crate::commands::pip::loggers::DefaultResolveLogger;
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
-use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{ExitStatus, OutputWriter, diagnostics};
 use crate::printer::Printer;
 
@@ -75,6 +85,7 @@ pub(crate) async fn pip_compile(
     extras: ExtrasSpecification,
     groups: GroupsSpecification,
     output_file: Option<&Path>,
+    constraint_output: Option<&Path>,
     format: Option<PipCompileFormat>,
     resolution_mode: ResolutionMode,
     prerelease_mode: PrereleaseMode,
@@ -88,11 +99,21 @@ pub(crate) async fn pip_compile(
     include_annotations: bool,
     include_header: bool,
     custom_compile_command: Option<String>,
+    header_comment: Option<String>,
     include_index_url: bool,
     include_find_links: bool,
     include_build_options: bool,
     include_marker_expression: bool,
     include_index_annotation: bool,
+    direct_only: bool,
+    include_package_urls: bool,
+    include_license: bool,
+    include_extras_annotation: bool,
+    include_tree: bool,
+    group_by_extra: bool,
+    strict_extras: bool,
+    check_url: Option<&IndexUrl>,
+    fail_on_license: Option<&spdx::Expression>,
     index_locations: IndexLocations,
     index_strategy: IndexStrategy,
     torch_backend: Option<TorchMode>,
@@ -107,6 +128,7 @@ pub(crate) async fn pip_compile(
     build_options: BuildOptions,
     install_mirrors: PythonInstallMirrors,
     mut python_version: Option<PythonVersion>,
+    python_requires: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
     python_downloads: PythonDownloads,
     universal: bool,
@@ -148,6 +170,28 @@ pub(crate) async fn pip_compile(
         }
     });
 
+    // Constraints can only be written alongside `requirements.txt` output.
+    if constraint_output.is_some() && matches!(format, PipCompileFormat::PylockToml) {
+        return Err(anyhow!(
+            "`--constraint-output` is not supported for `pylock.toml` output"
+        ));
+    }
+
+    // The dependency tree is written alongside the output file.
+    let tree_output = if include_tree {
+        if matches!(format, PipCompileFormat::PylockToml) {
+            return Err(anyhow!(
+                "`--emit-tree` is not supported for `pylock.toml` output"
+            ));
+        }
+        let Some(output_file) = output_file else {
+            return Err(anyhow!("`--emit-tree` requires `--output-file`"));
+        };
+        Some(output_file.with_extension("tree.txt"))
+    } else {
+        None
+    };
+
     // If the user is exporting to PEP 751, ensure the filename matches the specification.
     if matches!(format, PipCompileFormat::PylockToml) {
         if let Some(file_name) = output_file
@@ -162,6 +206,12 @@ pub(crate) async fn pip_compile(
         }
     }
 
+    // Read the custom comment block, if any.
+    let header_comment = header_comment
+        .as_deref()
+        .map(header_comment_lines)
+        .transpose()?;
+
     // Respect `UV_PYTHON`
     if python.is_none() && python_version.is_none() {
         if let Ok(request) = std::env::var(EnvVars::UV_PYTHON) {
@@ -363,22 +413,26 @@ pub(crate) async fn pip_compile(
     // If we're resolving against a different Python version, use a separate index. Source
     // distributions will be built against the installed version, and so the index may contain
     // different package priorities than in the top-level resolution.
-    let top_level_index = if python_version.is_some() {
+    let top_level_index = if python_version.is_some() || python_requires.is_some() {
         InMemoryIndex::default()
     } else {
         state.index().clone()
     };
 
+    // Markers (and `Requires-Python`) are evaluated against `--python-requires`, if provided, while
+    // wheel tags continue to be derived from `--python-version`.
+    let marker_python_version = python_requires.as_ref().or(python_version.as_ref());
+
     // Determine the Python requirement, if the user requested a specific version.
     let python_requirement = if universal {
-        let requires_python = if let Some(python_version) = python_version.as_ref() {
+        let requires_python = if let Some(python_version) = marker_python_version {
             RequiresPython::greater_than_equal_version(&python_version.version)
         } else {
             let version = interpreter.python_minor_version();
             RequiresPython::greater_than_equal_version(&version)
         };
         PythonRequirement::from_requires_python(&interpreter, requires_python)
-    } else if let Some(python_version) = python_version.as_ref() {
+    } else if let Some(python_version) = marker_python_version {
         PythonRequirement::from_python_version(&interpreter, python_version)
     } else {
         PythonRequirement::from_interpreter(&interpreter)
@@ -403,7 +457,7 @@ pub(crate) async fn pip_compile(
             &interpreter,
         )?;
         let marker_env = resolution_markers(
-            python_version.as_ref(),
+            marker_python_version,
             python_platform.as_ref(),
             &interpreter,
         );
@@ -495,7 +549,13 @@ pub(crate) async fn pip_compile(
         let entries = client
             .fetch_all(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, tags.as_deref(), &hasher, &build_options)
+        FlatIndex::from_entries(
+            entries,
+            tags.as_deref(),
+            &hasher,
+            &build_options,
+            &WheelTags::default(),
+        )
     };
 
     // Determine whether to enable build isolation.
@@ -564,6 +624,55 @@ pub(crate) async fn pip_compile(
         .artifact_environments(artifact_environments)
         .build();
 
+    // If requested, determine the enabled extras that request each requirement, to group the
+    // output by extra. The source tree metadata is cached in the index for the resolution below.
+    let extra_groups = if group_by_extra {
+        let resolutions = SourceTreeResolver::new(
+            &extras,
+            &hasher,
+            &top_level_index,
+            DistributionDatabase::new(
+                &client,
+                &build_dispatch,
+                concurrency.downloads_semaphore.clone(),
+            ),
+        )
+        .with_reporter(Arc::new(ResolverReporter::from(printer)))
+        .resolve(source_trees.iter())
+        .await?;
+
+        let mut extra_groups = BTreeMap::<PackageName, BTreeSet<ExtraName>>::new();
+        for (name, requested_by) in resolutions
+            .into_iter()
+            .flat_map(|resolution| resolution.requirement_extras)
+        {
+            match extra_groups.entry(name) {
+                Entry::Vacant(entry) => {
+                    entry.insert(requested_by);
+                }
+                Entry::Occupied(mut entry) => {
+                    // A requirement that is requested without an extra anywhere isn't grouped.
+                    if entry.get().is_empty() || requested_by.is_empty() {
+                        entry.get_mut().clear();
+                    } else {
+                        entry.get_mut().extend(requested_by);
+                    }
+                }
+            }
+        }
+
+        // Requirements that are provided directly (e.g., via `requirements.in`) aren't grouped.
+        for requirement in &requirements {
+            if let UnresolvedRequirement::Named(requirement) = &requirement.requirement {
+                extra_groups.insert(requirement.name.clone(), BTreeSet::new());
+            }
+        }
+
+        Some(extra_groups)
+    } else {
+        None
+    };
+
     // Resolve the requirements.
     let resolution = match operations::resolve(
         requirements,
@@ -606,28 +715,122 @@ pub(crate) async fn pip_compile(
         }
     };
 
+    // If requested, treat missing extras as errors, before writing any output.
+    if strict_extras {
+        let missing_extras = resolution
+            .diagnostics()
+            .iter()
+            .filter(|diagnostic| matches!(diagnostic, ResolutionDiagnostic::MissingExtra { .. }))
+            .collect::<Vec<_>>();
+        if !missing_extras.is_empty() {
+            operations::report_resolution_errors(missing_extras, printer)?;
+            return Ok(ExitStatus::Failure);
+        }
+    }
+
+    // If requested, verify that each pinned package is available at the given index, before
+    // writing any output.
+    if let Some(check_url) = check_url {
+        let unavailable = find_unavailable_packages(
+            &resolution,
+            check_url,
+            &client_builder,
+            &cache,
+            &concurrency,
+        )
+        .await?;
+        if !unavailable.is_empty() {
+            for (name, version, reason) in unavailable {
+                let message = match reason {
+                    Unavailable::Missing => {
+                        format!("`{name}=={version}` is not available at: {check_url}")
+                    }
+                    Unavailable::Yanked => {
+                        format!("`{name}=={version}` is yanked at: {check_url}")
+                    }
+                };
+                writeln!(
+                    printer.stderr(),
+                    "{}{} {}",
+                    "error".red().bold(),
+                    ":".bold(),
+                    message.bold()
+                )?;
+            }
+            return Ok(ExitStatus::Failure);
+        }
+    }
+
+    // If requested, read the license of each package from its wheel metadata. Licenses aren't
+    // part of the cached resolver metadata, so this requires fetching (or building) each wheel.
+    let licenses = if include_license || fail_on_license.is_some() {
+        let tags = match tags.as_deref() {
+            Some(tags) => tags,
+            None => interpreter.tags()?,
+        };
+        let database = DistributionDatabase::new(
+            &client,
+            &build_dispatch,
+            concurrency.downloads_semaphore.clone(),
+        );
+        Some(find_licenses(&resolution, &database, tags, &hasher, &concurrency).await?)
+    } else {
+        None
+    };
+
+    // If requested, reject any package that's only available under a rejected license, before
+    // writing any output.
+    if let (Some(fail_on_license), Some(licenses)) = (fail_on_license, licenses.as_ref()) {
+        let rejected = find_rejected_licenses(licenses, fail_on_license);
+        if !rejected.is_empty() {
+            for (name, version, license) in rejected {
+                writeln!(
+                    printer.stderr(),
+                    "{}{} {}",
+                    "error".red().bold(),
+                    ":".bold(),
+                    format!(
+                        "`{name}=={version}` is licensed under a rejected license: `{license}`"
+                    )
+                    .bold()
+                )?;
+            }
+            return Ok(ExitStatus::Failure);
+        }
+    }
+
     // Write the resolved dependencies to the output channel.
     let mut writer = OutputWriter::new(!quiet || output_file.is_none(), output_file);
 
+    // If requested, write the resolved versions to a separate constraints file.
+    let mut constraint_writer = constraint_output
+        .map(|constraint_output| OutputWriter::new(false, Some(constraint_output)));
+
+    // If requested, write the dependency tree to a separate file.
+    let mut tree_writer = tree_output
+        .as_deref()
+        .map(|tree_output| OutputWriter::new(false, Some(tree_output)));
+
+    let mut header = header_comment.unwrap_or_default();
     if include_header {
-        writeln!(
-            writer,
-            "{}",
-            "# This file was autogenerated by uv via the following command:".green()
-        )?;
-        writeln!(
-            writer,
-            "{}",
-            format!(
-                "#    {}",
-                cmd(
-                    include_index_url,
-                    include_find_links,
-                    custom_compile_command
-                )
+        header.push("# This file was autogenerated by uv via the following command:".to_string());
+        header.push(format!(
+            "#    {}",
+            cmd(
+                include_index_url,
+                include_find_links,
+                custom_compile_command
             )
-            .green()
-        )?;
+        ));
+    }
+    for line in &header {
+        writeln!(writer, "{}", line.green())?;
+        if let Some(constraint_writer) = constraint_writer.as_mut() {
+            writeln!(constraint_writer, "{line}")?;
+        }
+        if let Some(tree_writer) = tree_writer.as_mut() {
+            writeln!(tree_writer, "{line}")?;
+        }
     }
 
     match format {
@@ -706,6 +909,12 @@ pub(crate) async fn pip_compile(
                 writeln!(writer)?;
             }
 
+            if direct_only {
+                warn_user!(
+                    "The `--emit-direct-only` output omits transitive dependencies and is not a complete locked environment; installing it may select different versions of transitive dependencies, or fail if they have incompatible requirements"
+                );
+            }
+
             write!(
                 writer,
                 "{}",
@@ -713,14 +922,39 @@ pub(crate) async fn pip_compile(
                     &resolution,
                     &resolver_env,
                     &no_emit_packages,
+                    direct_only,
                     generate_hashes,
                     include_extras,
                     include_markers || universal,
                     include_annotations,
                     include_index_annotation,
+                    include_package_urls,
+                    licenses.as_ref().filter(|_| include_license),
+                    include_extras_annotation,
+                    extra_groups.as_ref(),
                     annotation_style,
                 )
             )?;
+
+            if let Some(constraint_writer) = constraint_writer.as_mut() {
+                write!(
+                    constraint_writer,
+                    "{}",
+                    DisplayResolutionConstraints::new(
+                        &resolution,
+                        &no_emit_packages,
+                        include_markers || universal,
+                    )
+                )?;
+            }
+
+            if let Some(tree_writer) = tree_writer.as_mut() {
+                write!(
+                    tree_writer,
+                    "{}",
+                    DisplayResolutionTree::new(&resolution, &resolver_env)
+                )?;
+            }
         }
         PipCompileFormat::PylockToml => {
             if include_marker_expression {
@@ -748,6 +982,11 @@ pub(crate) async fn pip_compile(
                     "The `--emit-index-annotation` option is not supported for `pylock.toml` output"
                 );
             }
+            if direct_only {
+                warn_user!(
+                    "The `--emit-direct-only` option is not supported for `pylock.toml` output"
+                );
+            }
 
             // Determine the directory relative to which the output file should be written.
             let output_file = output_file.map(std::path::absolute).transpose()?;
@@ -788,6 +1027,12 @@ pub(crate) async fn pip_compile(
 
     // Commit the output to disk.
     writer.commit().await?;
+    if let Some(constraint_writer) = constraint_writer {
+        constraint_writer.commit().await?;
+    }
+    if let Some(tree_writer) = tree_writer {
+        tree_writer.commit().await?;
+    }
 
     // Notify the user of any resolution diagnostics.
     operations::diagnose_resolution(resolution.diagnostics(), printer)?;
@@ -795,6 +1040,195 @@ pub(crate) async fn pip_compile(
     Ok(ExitStatus::Success)
 }
 
+/// Read the SPDX license expression of each pinned package from the `METADATA` file of its wheel,
+/// building the wheel from source if necessary.
+///
+/// Packages whose license can't be determined are omitted.
+async fn find_licenses(
+    resolution: &ResolverOutput,
+    database: &DistributionDatabase<'_, BuildDispatch<'_>>,
+    tags: &Tags,
+    hasher: &HashStrategy,
+    concurrency: &Concurrency,
+) -> Result<BTreeMap<PackageName, BTreeMap<Version, String>>> {
+    let licenses = futures::stream::iter(resolution.packages())
+        .filter_map(async |(name, version, dist)| match dist {
+            ResolvedDist::Installable { dist, .. } => Some((name, version, dist)),
+            ResolvedDist::Installed { .. } => None,
+        })
+        .map(async |(name, version, dist)| {
+            debug!("Reading license for: {dist}");
+            let wheel = database
+                .get_or_build_wheel(dist, tags, hasher.get(dist.as_ref()))
+                .await?;
+            Ok::<_, uv_distribution::Error>((name, version, wheel.license()?))
+        })
+        .buffer_unordered(concurrency.downloads)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let mut by_name = BTreeMap::<PackageName, BTreeMap<Version, String>>::new();
+    for (name, version, license) in licenses {
+        if let Some(license) = license {
+            by_name
+                .entry(name.clone())
+                .or_default()
+                .insert(version.clone(), license);
+        }
+    }
+    Ok(by_name)
+}
+
+/// Return each pinned package whose license can't be satisfied without one of the licenses in
+/// the given expression, along with its license.
+///
+/// Packages whose license is unknown aren't rejected.
+fn find_rejected_licenses<'a>(
+    licenses: &'a BTreeMap<PackageName, BTreeMap<Version, String>>,
+    fail_on_license: &spdx::Expression,
+) -> Vec<(&'a PackageName, &'a Version, &'a str)> {
+    let rejected = fail_on_license
+        .requirements()
+        .filter_map(|requirement| requirement.req.license.id())
+        .collect::<Vec<_>>();
+    licenses
+        .iter()
+        .flat_map(|(name, versions)| {
+            versions
+                .iter()
+                .map(move |(version, license)| (name, version, license.as_str()))
+        })
+        .filter_map(|(name, version, license)| {
+            let expression = spdx::Expression::parse_mode(license, spdx::ParseMode::STRICT).ok()?;
+            let accepted = expression.evaluate(|requirement| {
+                requirement
+                    .license
+                    .id()
+                    .is_none_or(|id| !rejected.contains(&id))
+            });
+            (!accepted).then_some((name, version, license))
+        })
+        .collect()
+}
+
+/// The reason a pinned package is unavailable at the index passed to `--check-url`.
+#[derive(Debug, Copy, Clone)]
+enum Unavailable {
+    /// The index doesn't provide any distributions for the pinned version.
+    Missing,
+    /// Every distribution for the pinned version is yanked.
+    Yanked,
+}
+
+/// Query the given index for each package that was resolved from a registry, returning those
+/// whose pinned version is unavailable.
+async fn find_unavailable_packages<'a>(
+    resolution: &'a ResolverOutput,
+    check_url: &IndexUrl,
+    client_builder: &BaseClientBuilder<'_>,
+    cache: &Cache,
+    concurrency: &Concurrency,
+) -> Result<Vec<(&'a PackageName, &'a Version, Unavailable)>> {
+    let packages = resolution.registry_packages().collect::<BTreeSet<_>>();
+
+    // Bypass any cached responses, as the index may have changed since the resolution.
+    let cache = cache.clone().with_refresh(Refresh::from_args(
+        None,
+        packages.iter().map(|(name, _)| (*name).clone()).collect(),
+    ));
+    let client = RegistryClientBuilder::new(client_builder.clone(), cache)
+        .index_locations(IndexLocations::new(
+            vec![Index::from_index_url(check_url.clone())],
+            Vec::new(),
+            false,
+        ))
+        .build()?;
+    let capabilities = IndexCapabilities::default();
+
+    let mut unavailable = futures::stream::iter(packages)
+        .map(async |(name, version)| {
+            debug!("Checking for `{name}=={version}` at: {check_url}");
+            let archives = match client
+                .simple_detail(
+                    name,
+                    Some(IndexMetadataRef::from(check_url)),
+                    &capabilities,
+                    &concurrency.downloads_semaphore,
+                )
+                .await
+            {
+                Ok(archives) => archives,
+                Err(err) => {
+                    return match err.kind() {
+                        uv_client::ErrorKind::RemotePackageNotFound(_) => {
+                            Ok(Some((name, version, Unavailable::Missing)))
+                        }
+                        _ => Err(err),
+                    };
+                }
+            };
+
+            let mut reason = Unavailable::Missing;
+            for (_, archive) in archives {
+                let MetadataFormat::Simple(archive) = archive else {
+                    continue;
+                };
+                let metadata = OwnedArchive::deserialize(&archive);
+                for datum in metadata.iter().filter(|datum| datum.version == *version) {
+                    let files = datum
+                        .files
+                        .wheels
+                        .iter()
+                        .map(|wheel| &wheel.file)
+                        .chain(datum.files.source_dists.iter().map(|sdist| &sdist.file));
+                    for file in files {
+                        if file
+                            .yanked
+                            .as_ref()
+                            .is_some_and(|yanked| yanked.is_yanked())
+                        {
+                            reason = Unavailable::Yanked;
+                        } else {
+                            return Ok(None);
+                        }
+                    }
+                }
+            }
+            Ok::<_, uv_client::Error>(Some((name, version, reason)))
+        })
+        .buffer_unordered(concurrency.downloads)
+        .try_filter_map(async |result| Ok(result))
+        .try_collect::<Vec<_>>()
+        .await?;
+    unavailable.sort_unstable_by_key(|(name, version, _)| (*name, *version));
+
+    Ok(unavailable)
+}
+
+/// Format the lines of the custom comment block included at the top of the output file.
+///
+/// If the value is of the form `@<path>`, the comment block is read from the given file.
+/// Otherwise, `\n` escapes are treated as line breaks.
+fn header_comment_lines(header_comment: &str) -> Result<Vec<String>> {
+    let text = if let Some(path) = header_comment.strip_prefix('@') {
+        fs_err::read_to_string(path)?
+    } else {
+        header_comment.replace("\\n", "\n")
+    };
+    Ok(text
+        .lines()
+        .map(|line| {
+            if line.starts_with('#') {
+                line.to_string()
+            } else if line.is_empty() {
+                "#".to_string()
+            } else {
+                format!("# {line}")
+            }
+        })
+        .collect())
+}
+
 /// Format the uv command used to generate the output file.
 fn cmd(
     include_index_url: bool,
@@ -821,6 +1255,7 @@ fn cmd(
                     || arg.starts_with("-i=")
                     || arg.starts_with("--index=")
                     || arg.starts_with("--default-index=")
+                    || arg.starts_with("--check-url=")
                 {
                     // Reset state; skip this iteration.
                     *skip_next = None;
@@ -833,6 +1268,7 @@ fn cmd(
                     || arg == "-i"
                     || arg == "--index"
                     || arg == "--default-index"
+                    || arg == "--check-url"
                 {
                     *skip_next = Some(true);
                     return Some(None);
@@ -855,6 +1291,16 @@ fn cmd(
                 }
             }
 
+            // Always skip the `--header-comment`, as it's already included verbatim.
+            if arg == "--header-comment" {
+                *skip_next = Some(true);
+                return Some(None);
+            }
+            if arg.starts_with("--header-comment=") {
+                *skip_next = None;
+                return Some(None);
+            }
+
             // Always skip the `--upgrade` flag.
             if arg == "--upgrade" || arg == "-U" {
                 *skip_next = None;
@@ -0,0 +1,152 @@
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use anyhow::{Context, Result, bail};
+use itertools::Itertools;
+use serde::Serialize;
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use uv_distribution_types::Name;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pep440::Version;
+use uv_python::PythonEnvironment;
+use uv_static::EnvVars;
+
+use crate::commands::pip::operations::{ChangedDist, Changelog, ShortSpecifier};
+
+/// Scripts to run before and after modifying an environment, i.e., `--hooks-pre` and
+/// `--hooks-post`.
+///
+/// Each script receives the changes to the environment as JSON on stdin, with the names of the
+/// installed packages in [`EnvVars::UV_HOOK_PACKAGES`] and the environment root in
+/// [`EnvVars::UV_HOOK_PREFIX`]. Scripts are not run if the environment is already up-to-date.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct InstallHooks {
+    /// The script to run once the installation plan is known, before modifying the environment.
+    pre: Option<PathBuf>,
+    /// The script to run after modifying the environment.
+    post: Option<PathBuf>,
+}
+
+impl InstallHooks {
+    pub(crate) fn new(pre: Option<PathBuf>, post: Option<PathBuf>) -> Self {
+        Self { pre, post }
+    }
+
+    /// Run the `--hooks-pre` script, if any, with the planned changes to the environment.
+    pub(crate) async fn run_pre(
+        &self,
+        plan: impl FnOnce() -> Changelog,
+        venv: &PythonEnvironment,
+    ) -> Result<()> {
+        if let Some(script) = &self.pre {
+            run_hook("--hooks-pre", script, &plan(), venv).await?;
+        }
+        Ok(())
+    }
+
+    /// Run the `--hooks-post` script, if any, with the changes made to the environment.
+    pub(crate) async fn run_post(
+        &self,
+        changelog: &Changelog,
+        venv: &PythonEnvironment,
+    ) -> Result<()> {
+        if let Some(script) = &self.post {
+            run_hook("--hooks-post", script, changelog, venv).await?;
+        }
+        Ok(())
+    }
+}
+
+/// The changes to an environment, as passed to a hook on stdin.
+#[derive(Debug, Serialize)]
+struct HookPlan<'a> {
+    install: Vec<HookPackage<'a>>,
+    reinstall: Vec<HookPackage<'a>>,
+    uninstall: Vec<HookPackage<'a>>,
+}
+
+impl<'a> From<&'a Changelog> for HookPlan<'a> {
+    fn from(changelog: &'a Changelog) -> Self {
+        Self {
+            install: HookPackage::sorted(&changelog.installed),
+            reinstall: HookPackage::sorted(&changelog.reinstalled),
+            uninstall: HookPackage::sorted(&changelog.uninstalled),
+        }
+    }
+}
+
+/// A package in a [`HookPlan`], identified by its version or, for direct URL requirements, by
+/// its URL.
+#[derive(Debug, Serialize)]
+struct HookPackage<'a> {
+    name: &'a PackageName,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<&'a Version>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+}
+
+impl<'a> HookPackage<'a> {
+    fn sorted(dists: impl IntoIterator<Item = &'a ChangedDist>) -> Vec<Self> {
+        dists
+            .into_iter()
+            .map(|dist| match dist.short_specifier() {
+                ShortSpecifier::Version(version) => Self {
+                    name: dist.name(),
+                    version: Some(version),
+                    url: None,
+                },
+                ShortSpecifier::Url(url) => Self {
+                    name: dist.name(),
+                    version: None,
+                    url: Some(url.to_string()),
+                },
+            })
+            .sorted_by(|a, b| a.name.cmp(b.name).then(a.version.cmp(&b.version)))
+            .collect()
+    }
+}
+
+/// Run a hook script, passing the changes to the environment on stdin.
+async fn run_hook(
+    flag: &str,
+    script: &Path,
+    changelog: &Changelog,
+    venv: &PythonEnvironment,
+) -> Result<()> {
+    let plan = HookPlan::from(changelog);
+    let packages = plan
+        .install
+        .iter()
+        .chain(&plan.reinstall)
+        .map(|package| package.name)
+        .join(" ");
+    let stdin = serde_json::to_vec(&plan)?;
+
+    let mut child = Command::new(script)
+        .env(EnvVars::UV_HOOK_PACKAGES, packages)
+        .env(EnvVars::UV_HOOK_PREFIX, venv.root())
+        .stdin(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to run `{flag}` script `{}`", script.user_display()))?;
+
+    // A script that doesn't read its input closes the pipe early, which isn't an error.
+    if let Some(mut child_stdin) = child.stdin.take()
+        && let Err(err) = child_stdin.write_all(&stdin).await
+        && err.kind() != std::io::ErrorKind::BrokenPipe
+    {
+        return Err(err.into());
+    }
+
+    let status = child.wait().await?;
+    if !status.success() {
+        bail!(
+            "`{flag}` script `{}` failed with {status}",
+            script.user_display()
+        );
+    }
+    Ok(())
+}
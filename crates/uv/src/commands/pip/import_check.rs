@@ -0,0 +1,200 @@
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use futures::future::try_join_all;
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tokio::process::Command;
+use tracing::debug;
+
+use uv_distribution_types::{InstalledDist, Name};
+use uv_fs::Simplified;
+use uv_installer::SitePackages;
+use uv_python::PythonEnvironment;
+use uv_static::EnvVars;
+
+use crate::commands::pip::operations::Changelog;
+use crate::printer::Printer;
+
+/// Whether to verify that newly installed packages can be imported, i.e., `--post-install-check`
+/// and `--strict-post-install-check`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum PostInstallCheck {
+    /// Don't attempt to import the installed packages.
+    #[default]
+    Disabled,
+    /// Warn about any installed package that fails to import.
+    Warn,
+    /// Fail if any installed package fails to import.
+    Strict,
+}
+
+impl PostInstallCheck {
+    /// Determine the [`PostInstallCheck`] from the command-line arguments.
+    pub(crate) fn from_args(check: bool, strict: bool) -> Self {
+        match (check, strict) {
+            (_, true) => Self::Strict,
+            (true, false) => Self::Warn,
+            (false, false) => Self::Disabled,
+        }
+    }
+
+    /// Import every package that was installed or reinstalled in the given [`Changelog`], reporting
+    /// any that fail.
+    ///
+    /// Returns `false` if a package failed to import and the check is [`PostInstallCheck::Strict`].
+    pub(crate) async fn run(
+        self,
+        changelog: &Changelog,
+        venv: &PythonEnvironment,
+        printer: Printer,
+    ) -> Result<bool> {
+        if self == Self::Disabled {
+            return Ok(true);
+        }
+
+        let site_packages = SitePackages::from_environment(venv)?;
+        let dists = changelog
+            .installed
+            .iter()
+            .chain(&changelog.reinstalled)
+            .map(Name::name)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .flat_map(|name| site_packages.get_packages(name))
+            .collect::<Vec<_>>();
+
+        let failures = try_join_all(dists.iter().map(|dist| check_import(dist, venv))).await?;
+
+        let mut passed = true;
+        for (dist, error) in dists
+            .iter()
+            .zip(failures)
+            .filter_map(|(dist, error)| Some((dist, error?)))
+        {
+            passed = false;
+            writeln!(
+                printer.stderr(),
+                "{}{} Package `{}` was installed, but failed to import: {}",
+                "warning".yellow().bold(),
+                ":".bold(),
+                format!("{}=={}", dist.name(), dist.version()).bold(),
+                error
+            )?;
+        }
+
+        Ok(passed || self == Self::Warn)
+    }
+}
+
+/// Import the top-level modules of an installed distribution in a fresh interpreter, returning
+/// the error if the import fails.
+async fn check_import(dist: &InstalledDist, venv: &PythonEnvironment) -> Result<Option<String>> {
+    let modules = top_level_modules(dist.install_path())?;
+    if modules.is_empty() {
+        debug!(
+            "No importable modules found for: {}=={}",
+            dist.name(),
+            dist.version()
+        );
+        return Ok(None);
+    }
+
+    let source = format!("import {}", modules.iter().join(", "));
+    debug!(
+        "Checking imports for {}=={}: `{source}`",
+        dist.name(),
+        dist.version()
+    );
+
+    let mut command = Command::new(venv.python_executable());
+    command.arg("-c").arg(&source);
+
+    // `--target` and `--prefix` directories aren't on the interpreter's default search path.
+    if venv.interpreter().is_target() || venv.interpreter().is_prefix() {
+        command.env(
+            EnvVars::PYTHONPATH,
+            std::env::join_paths(venv.interpreter().site_packages().map(Cow::into_owned))?,
+        );
+    }
+
+    let output = command.output().await.with_context(|| {
+        format!(
+            "Failed to run `{}` to check imports",
+            venv.python_executable().user_display()
+        )
+    })?;
+    if output.status.success() {
+        return Ok(None);
+    }
+
+    // Report the last line of the traceback, i.e., the exception itself.
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let error = stderr
+        .lines()
+        .rev()
+        .find(|line| !line.trim().is_empty())
+        .map(str::trim)
+        .map(ToString::to_string)
+        .unwrap_or_else(|| format!("Python exited with {}", output.status));
+    Ok(Some(error))
+}
+
+/// Determine the top-level modules provided by an installed distribution.
+///
+/// Prefers the `top_level.txt` written by setuptools, falling back to the top-level entries in
+/// the `RECORD` file.
+fn top_level_modules(install_path: &Path) -> Result<BTreeSet<String>> {
+    match fs_err::read_to_string(install_path.join("top_level.txt")) {
+        Ok(contents) => {
+            let modules = contents
+                .lines()
+                .map(str::trim)
+                .filter(|module| is_identifier(module))
+                .map(ToString::to_string)
+                .collect::<BTreeSet<_>>();
+            if !modules.is_empty() {
+                return Ok(modules);
+            }
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => return Err(err.into()),
+    }
+
+    let record = match fs_err::File::open(install_path.join("RECORD")) {
+        Ok(record) => uv_install_wheel::read_record(record)?,
+        // Only `.dist-info` installations have a `RECORD` file.
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(BTreeSet::new()),
+        Err(err) => return Err(err.into()),
+    };
+
+    Ok(record
+        .iter()
+        .filter_map(|entry| {
+            let mut components = entry.path.split('/');
+            let first = components.next()?;
+            let module = if components.next().is_some() {
+                // A package directory, e.g., `foo/__init__.py`.
+                first
+            } else {
+                // A top-level module, e.g., `foo.py` or `foo.cpython-312-x86_64-linux-gnu.so`.
+                let (stem, extension) = first.rsplit_once('.')?;
+                if !matches!(extension, "py" | "so" | "pyd") {
+                    return None;
+                }
+                stem.split('.').next()?
+            };
+            (is_identifier(module) && module != "__pycache__").then(|| module.to_string())
+        })
+        .collect())
+}
+
+/// Returns `true` if the name is a valid Python identifier, and so could be a top-level module.
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
@@ -1,5 +1,8 @@
-use std::collections::BTreeSet;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
 
+use anyhow::bail;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use tracing::{Level, debug, enabled, warn};
@@ -8,22 +11,23 @@ use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     BuildIsolation, BuildOptions, Concurrency, Constraints, DryRun, ExtrasSpecification,
-    HashCheckingMode, IndexStrategy, NoSources, Reinstall, Upgrade,
+    HashCheckingMode, IndexStrategy, NoSources, Reinstall, ShowPlan, Upgrade,
 };
 use uv_configuration::{KeyringProviderType, TargetTriple};
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::LoweredExtraBuildDependencies;
 use uv_distribution_types::{
-    ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations,
-    NameRequirementSpecification, Origin, PackageConfigSettings, Requirement, Resolution,
-    UnresolvedRequirementSpecification,
+    ConfigSettings, DependencyMetadata, ExtraBuildVariables, Index, IndexLocations, Name,
+    NameRequirementSpecification, Origin, PackageConfigSettings, Requirement, RequirementSource,
+    Resolution, UnresolvedRequirementSpecification,
 };
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_installer::{InstallationStrategy, SatisfiesResult, SitePackages};
 use uv_normalize::{DefaultExtras, DefaultGroups, PackageName};
+use uv_platform_tags::PlatformTag;
 use uv_preview::{Preview, PreviewFeature};
-use uv_pypi_types::Conflicts;
+use uv_pypi_types::{Conflicts, ResolverMarkerEnvironment};
 use uv_python::{
     EnvironmentPreference, Prefix, PythonDownloads, PythonEnvironment, PythonInstallation,
     PythonPreference, PythonRequest, PythonVersion, Target,
@@ -31,7 +35,7 @@ use uv_python::{
 use uv_requirements::{GroupsSpecification, RequirementsSource, RequirementsSpecification};
 use uv_resolver::{
     DependencyMode, ExcludeNewer, FlatIndex, OptionsBuilder, PrereleaseMode, PythonRequirement,
-    ResolutionMode, ResolverEnvironment,
+    ResolutionMode, ResolverEnvironment, WheelTags,
 };
 use uv_settings::PythonInstallMirrors;
 use uv_torch::{TorchMode, TorchSource, TorchStrategy};
@@ -40,8 +44,10 @@ use uv_warnings::warn_user;
 use uv_workspace::WorkspaceCache;
 use uv_workspace::pyproject::ExtraBuildDependencies;
 
+use crate::commands::pip::hooks::InstallHooks;
+use crate::commands::pip::import_check::PostInstallCheck;
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
-use crate::commands::pip::operations::Modifications;
+use crate::commands::pip::operations::{Changelog, Modifications};
 use crate::commands::pip::operations::{report_interpreter, report_target_environment};
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
 use crate::commands::pylock::{read_pylock_toml, resolve_pylock_toml};
@@ -57,10 +63,13 @@ pub(crate) async fn pip_install(
     overrides: &[RequirementsSource],
     excludes: &[RequirementsSource],
     build_constraints: &[RequirementsSource],
+    constraints_from_env: bool,
+    constraint_strict: bool,
     constraints_from_workspace: Vec<Requirement>,
     overrides_from_workspace: Vec<Requirement>,
     excludes_from_workspace: Vec<uv_normalize::PackageName>,
     build_constraints_from_workspace: Vec<Requirement>,
+    build_isolation_packages: &[Requirement],
     extras: &ExtrasSpecification,
     groups: &GroupsSpecification,
     resolution_mode: ResolutionMode,
@@ -87,6 +96,12 @@ pub(crate) async fn pip_install(
     modifications: Modifications,
     python_version: Option<PythonVersion>,
     python_platform: Option<TargetTriple>,
+    platform_override: Option<PlatformTag>,
+    wheel_tags: WheelTags,
+    record: Option<&Path>,
+    clean_env: bool,
+    hooks: InstallHooks,
+    post_install_check: PostInstallCheck,
     python_downloads: PythonDownloads,
     install_mirrors: PythonInstallMirrors,
     strict: bool,
@@ -102,6 +117,7 @@ pub(crate) async fn pip_install(
     cache: Cache,
     workspace_cache: WorkspaceCache,
     dry_run: DryRun,
+    show_plan: ShowPlan,
     printer: Printer,
     preview: Preview,
 ) -> anyhow::Result<ExitStatus> {
@@ -146,12 +162,20 @@ pub(crate) async fn pip_install(
         }
     }
 
+    // Read the constraints from the environment, if requested.
+    let constraints_from_env = if constraints_from_env {
+        operations::read_env_constraints()?
+    } else {
+        Vec::new()
+    };
+
     let constraints: Vec<NameRequirementSpecification> = constraints
         .iter()
         .cloned()
         .chain(
             constraints_from_workspace
                 .into_iter()
+                .chain(constraints_from_env)
                 .map(NameRequirementSpecification::from),
         )
         .collect();
@@ -223,7 +247,8 @@ pub(crate) async fn pip_install(
     // Lower the extra build dependencies, if any.
     let extra_build_requires =
         LoweredExtraBuildDependencies::from_non_lowered(extra_build_dependencies.clone())
-            .into_inner();
+            .into_inner()
+            .with_global(build_isolation_packages.iter().cloned());
 
     // Apply any `--target` or `--prefix` directories.
     let environment = if let Some(target) = target {
@@ -301,6 +326,12 @@ pub(crate) async fn pip_install(
         python_platform.as_ref(),
         interpreter,
     )?;
+    let tags = if let Some(platform_override) = platform_override {
+        debug!("Overriding the platform tags with: {platform_override}");
+        Cow::Owned(tags.with_platform_tag(&platform_override))
+    } else {
+        tags
+    };
 
     // Determine the set of installed packages.
     let site_packages = SitePackages::from_environment(&environment)?;
@@ -331,6 +362,15 @@ pub(crate) async fn pip_install(
             SatisfiesResult::Fresh {
                 recursive_requirements,
             } => {
+                if constraint_strict {
+                    check_constraints(
+                        &constraints,
+                        None,
+                        &site_packages,
+                        modifications,
+                        &marker_env,
+                    )?;
+                }
                 if enabled!(Level::DEBUG) {
                     for requirement in recursive_requirements
                         .iter()
@@ -430,7 +470,7 @@ pub(crate) async fn pip_install(
         let entries = client
             .fetch_all(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(&tags), &hasher, &build_options)
+        FlatIndex::from_entries(entries, Some(&tags), &hasher, &build_options, &wheel_tags)
     };
 
     // Determine whether to enable build isolation.
@@ -490,7 +530,15 @@ pub(crate) async fn pip_install(
         workspace_cache.clone(),
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_clean_env(clean_env);
+
+    // Keep the constraints for `--constraint-strict`, since the resolver consumes them.
+    let strict_constraints = if constraint_strict {
+        constraints.clone()
+    } else {
+        Vec::new()
+    };
 
     let (resolution, hasher) = if let Some(pylock) = pylock {
         let (install_path, lock) = read_pylock_toml(&pylock, &client_builder).await?;
@@ -534,6 +582,7 @@ pub(crate) async fn pip_install(
             .index_strategy(index_strategy)
             .torch_backend(torch_backend)
             .build_options(build_options.clone())
+            .wheel_tags(wheel_tags)
             .build();
 
         // Resolve the requirements.
@@ -581,6 +630,17 @@ pub(crate) async fn pip_install(
         (resolution, hasher)
     };
 
+    // Fail before modifying the environment if it wouldn't satisfy the constraints.
+    if constraint_strict {
+        check_constraints(
+            &strict_constraints,
+            Some(&resolution),
+            &site_packages,
+            modifications,
+            &marker_env,
+        )?;
+    }
+
     // Constrain any build requirements marked as `match-runtime = true`.
     let extra_build_requires = extra_build_requires.match_runtime(&resolution)?;
 
@@ -609,10 +669,11 @@ pub(crate) async fn pip_install(
         workspace_cache,
         concurrency.clone(),
         preview,
-    );
+    )
+    .with_clean_env(clean_env);
 
     // Sync the environment.
-    match operations::install(
+    let changelog = match operations::install(
         &resolution,
         site_packages,
         InstallationStrategy::Permissive,
@@ -631,13 +692,20 @@ pub(crate) async fn pip_install(
         &environment,
         Box::new(DefaultInstallLogger),
         installer_metadata,
+        &hooks,
         dry_run,
+        show_plan,
         printer,
         preview,
     )
     .await
     {
-        Ok(..) => {}
+        Ok(changelog) => {
+            if let Some(record) = record {
+                write_session_record(record, &changelog, &environment)?;
+            }
+            changelog
+        }
         Err(err) => {
             return diagnostics::OperationDiagnostic::with_system_certs(
                 client_builder.system_certs(),
@@ -645,7 +713,7 @@ pub(crate) async fn pip_install(
             .report(err)
             .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
         }
-    }
+    };
 
     // Notify the user of any resolution diagnostics.
     operations::diagnose_resolution(resolution.diagnostics(), printer)?;
@@ -662,5 +730,115 @@ pub(crate) async fn pip_install(
         )?;
     }
 
+    // Verify that the newly installed packages can be imported.
+    if !post_install_check
+        .run(&changelog, &environment, printer)
+        .await?
+    {
+        return Ok(ExitStatus::Failure);
+    }
+
     Ok(ExitStatus::Success)
 }
+
+/// Verify that every constraint that applies to the current platform is satisfied by the
+/// environment once the resolution is installed, for `--constraint-strict`.
+///
+/// The resolver applies constraints to the packages it resolves, but packages outside the
+/// resolution are left as-is, and a `pylock.toml` isn't resolved at all. Only version
+/// constraints are checked; a URL constraint can't be compared against an installed version.
+fn check_constraints(
+    constraints: &[NameRequirementSpecification],
+    resolution: Option<&Resolution>,
+    site_packages: &SitePackages,
+    modifications: Modifications,
+    marker_env: &ResolverMarkerEnvironment,
+) -> anyhow::Result<()> {
+    // Packages the resolution doesn't include are kept, unless the environment is synced exactly.
+    let mut versions = BTreeMap::new();
+    if matches!(modifications, Modifications::Sufficient) {
+        for dist in site_packages.iter() {
+            versions.insert(dist.name(), dist.version());
+        }
+    }
+    for dist in resolution.into_iter().flat_map(Resolution::distributions) {
+        if let Some(version) = dist.version() {
+            versions.insert(dist.name(), version);
+        }
+    }
+
+    let violations = constraints
+        .iter()
+        .map(|constraint| &constraint.requirement)
+        .filter(|constraint| constraint.evaluate_markers(Some(marker_env.markers()), &[]))
+        .filter_map(|constraint| {
+            let RequirementSource::Registry { specifier, .. } = &constraint.source else {
+                return None;
+            };
+            let version = versions.get(&constraint.name)?;
+            (!specifier.contains(version)).then(|| {
+                format!(
+                    "  `{}` does not satisfy the constraint `{}`",
+                    format!("{}=={version}", constraint.name).cyan(),
+                    constraint.cyan()
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    if violations.is_empty() {
+        return Ok(());
+    }
+
+    bail!(
+        "The environment would violate the constraints (with `{}`):\n{}",
+        "--constraint-strict".green(),
+        violations.join("\n")
+    )
+}
+
+/// Append a `RECORD` entry for every file installed in this session to the given file, followed
+/// by the name and version of the package that owns it.
+fn write_session_record(
+    path: &Path,
+    changelog: &Changelog,
+    environment: &PythonEnvironment,
+) -> anyhow::Result<()> {
+    let site_packages = SitePackages::from_environment(environment)?;
+    let names = changelog
+        .installed
+        .iter()
+        .chain(&changelog.reinstalled)
+        .map(Name::name)
+        .collect::<BTreeSet<_>>();
+
+    let file = fs_err::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .escape(b'"')
+        .from_writer(file);
+    for name in names {
+        for dist in site_packages.get_packages(name) {
+            let record = match fs_err::File::open(dist.install_path().join("RECORD")) {
+                Ok(record) => uv_install_wheel::read_record(record)?,
+                // Only `.dist-info` installations have a `RECORD` file.
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(err) => return Err(err.into()),
+            };
+            for entry in record {
+                writer.serialize((
+                    entry.path,
+                    entry.hash,
+                    entry.size,
+                    dist.name().as_str(),
+                    dist.version().to_string(),
+                ))?;
+            }
+        }
+    }
+    writer.flush()?;
+
+    Ok(())
+}
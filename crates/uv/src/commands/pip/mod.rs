@@ -8,6 +8,8 @@ use uv_python::{Interpreter, PythonVersion};
 pub(crate) mod check;
 pub(crate) mod compile;
 pub(crate) mod freeze;
+pub(crate) mod hooks;
+pub(crate) mod import_check;
 pub(crate) mod install;
 pub(crate) mod latest;
 pub(crate) mod list;
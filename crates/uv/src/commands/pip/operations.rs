@@ -3,9 +3,11 @@
 use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::fmt::Write;
 use std::path::PathBuf;
+use std::str::FromStr;
 use std::sync::Arc;
 
 use anyhow::{Context, anyhow};
+use console::Term;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
 use tracing::debug;
@@ -14,22 +16,24 @@ use uv_cache::Cache;
 use uv_client::{BaseClientBuilder, RegistryClient};
 use uv_configuration::{
     BuildOptions, Concurrency, Constraints, DependencyGroups, DryRun, Excludes,
-    ExtrasSpecification, Overrides, Reinstall, Upgrade,
+    ExtrasSpecification, Overrides, Reinstall, ShowPlan, Upgrade,
 };
 use uv_dispatch::BuildDispatch;
 use uv_distribution::{DistributionDatabase, SourcedDependencyGroups};
 use uv_distribution_types::{
     CachedDist, DependencyMetadata, Diagnostic, Dist, InstalledDist, InstalledVersion, LocalDist,
-    NameRequirementSpecification, Requirement, ResolutionDiagnostic, UnresolvedRequirement,
-    UnresolvedRequirementSpecification, VersionOrUrlRef,
+    NameRequirementSpecification, Requirement, RequirementSource, ResolutionDiagnostic,
+    UnresolvedRequirement, UnresolvedRequirementSpecification, VersionOrUrlRef,
+};
+use uv_distribution_types::{
+    DistributionMetadata, InstalledMetadata, Name, RemoteSource, Resolution,
 };
-use uv_distribution_types::{DistributionMetadata, InstalledMetadata, Name, Resolution};
 use uv_fs::Simplified;
 use uv_install_wheel::LinkMode;
 use uv_installer::{InstallationStrategy, Plan, Planner, Preparer, SitePackages};
 use uv_normalize::PackageName;
-use uv_pep440::Version;
-use uv_pep508::{MarkerEnvironment, RequirementOrigin, VerbatimUrl};
+use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+use uv_pep508::{MarkerEnvironment, MarkerTree, RequirementOrigin, VerbatimUrl};
 use uv_platform_tags::Tags;
 use uv_preview::Preview;
 use uv_pypi_types::{Conflicts, ResolverMarkerEnvironment};
@@ -43,13 +47,15 @@ use uv_resolver::{
     DependencyMode, Exclusions, FlatIndex, InMemoryIndex, Manifest, Options, Preference,
     Preferences, PythonRequirement, Resolver, ResolverEnvironment, ResolverOutput, UpgradePackages,
 };
+use uv_static::EnvVars;
 use uv_tool::InstalledTools;
 use uv_types::{BuildContext, HashStrategy, InFlight, InstalledPackagesProvider};
 use uv_warnings::warn_user;
 
-use crate::commands::compile_bytecode;
+use crate::commands::pip::hooks::InstallHooks;
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
 use crate::commands::reporters::{InstallReporter, PrepareReporter, ResolverReporter};
+use crate::commands::{compile_bytecode, human_readable_bytes};
 use crate::printer::Printer;
 
 /// Consolidate the requirements for an installation.
@@ -103,6 +109,55 @@ pub(crate) async fn read_constraints(
     )
 }
 
+/// Read the constraints from the `UV_CONSTRAINT_<NAME>` environment variables.
+///
+/// A bare version (e.g., `UV_CONSTRAINT_NUMPY=1.26.4`) pins the package to that version; any
+/// other value is parsed as a version specifier (e.g., `UV_CONSTRAINT_NUMPY=">=1.26,<2"`).
+pub(crate) fn read_env_constraints() -> Result<Vec<Requirement>, Error> {
+    let prefix = EnvVars::constraint("");
+
+    let mut constraints = Vec::new();
+    for (key, value) in std::env::vars_os() {
+        let Some(key) = key.to_str() else {
+            continue;
+        };
+        let Some(name) = key.strip_prefix(&prefix) else {
+            continue;
+        };
+        if name.is_empty() {
+            return Err(anyhow!("`{key}` is missing a package name").into());
+        }
+        let name = PackageName::from_str(name)
+            .with_context(|| format!("`{key}` does not refer to a valid package name"))?;
+        let value = value
+            .to_str()
+            .ok_or_else(|| anyhow!("`{key}` is not valid UTF-8"))?
+            .trim();
+        let specifier = if let Ok(version) = Version::from_str(value) {
+            VersionSpecifiers::from(VersionSpecifier::equals_version(version))
+        } else {
+            VersionSpecifiers::from_str(value)
+                .with_context(|| format!("`{key}` is not a valid version or version specifier"))?
+        };
+        debug!("Read constraint from `{key}`: `{name}{specifier}`");
+        constraints.push(Requirement {
+            name,
+            extras: Box::new([]),
+            groups: Box::new([]),
+            marker: MarkerTree::default(),
+            source: RequirementSource::Registry {
+                specifier,
+                index: None,
+                conflict: None,
+            },
+            origin: None,
+        });
+    }
+    constraints.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(constraints)
+}
+
 /// Resolve a set of requirements, similar to running `pip compile`.
 pub(crate) async fn resolve<InstalledPackages: InstalledPackagesProvider>(
     requirements: Vec<UnresolvedRequirementSpecification>,
@@ -577,7 +632,9 @@ pub(crate) async fn install(
     venv: &PythonEnvironment,
     logger: Box<dyn InstallLogger>,
     installer_metadata: bool,
+    hooks: &InstallHooks,
     dry_run: DryRun,
+    show_plan: ShowPlan,
     printer: Printer,
     preview: Preview,
 ) -> Result<Changelog, Error> {
@@ -639,6 +696,41 @@ pub(crate) async fn install(
         return Ok(Changelog::default());
     }
 
+    // If requested, print the plan and confirm that the user wants to proceed.
+    if show_plan.enabled()
+        && !confirm_plan(
+            &cached,
+            &remote,
+            &reinstalls,
+            &extraneous,
+            show_plan,
+            printer,
+        )?
+    {
+        return Err(Error::Aborted);
+    }
+
+    hooks
+        .run_pre(
+            || {
+                Changelog::new(
+                    remote.iter().cloned().map(ChangedDist::Remote).chain(
+                        cached
+                            .iter()
+                            .cloned()
+                            .map(|dist| ChangedDist::Local(dist.into())),
+                    ),
+                    reinstalls
+                        .iter()
+                        .chain(&extraneous)
+                        .cloned()
+                        .map(|dist| ChangedDist::Local(dist.into())),
+                )
+            },
+            venv,
+        )
+        .await?;
+
     // Partition into two sets: those that require build isolation, and those that disable it. This
     // is effectively a heuristic to make `--no-build-isolation` work "more often" by way of giving
     // `--no-build-isolation` packages "access" to the rest of the environment.
@@ -721,6 +813,8 @@ pub(crate) async fn install(
     // Notify the user of any environment modifications.
     logger.on_complete(&changelog, printer, dry_run)?;
 
+    hooks.run_post(&changelog, venv).await?;
+
     Ok(changelog)
 }
 
@@ -1052,6 +1146,110 @@ fn report_dry_run(
     Ok(changelog)
 }
 
+/// Print the installation plan and, with [`ShowPlan::Confirm`], prompt the user to proceed.
+///
+/// Packages that are both removed and installed are reported as upgrades (or reinstalls). The
+/// download size is shown for any distribution that isn't in the cache, if the index provides it.
+///
+/// Returns `false` if the user declined. If stderr isn't a terminal, the plan is printed without
+/// prompting.
+fn confirm_plan(
+    cached: &[CachedDist],
+    remote: &[Arc<Dist>],
+    reinstalls: &[InstalledDist],
+    extraneous: &[InstalledDist],
+    show_plan: ShowPlan,
+    printer: Printer,
+) -> Result<bool, Error> {
+    let installs = remote
+        .iter()
+        .map(|dist| (ChangedDist::Remote(dist.clone()), dist.size()))
+        .chain(
+            cached
+                .iter()
+                .map(|dist| (ChangedDist::Local(dist.clone().into()), None)),
+        )
+        .sorted_unstable_by(|(a, _), (b, _)| a.name().cmp(b.name()))
+        .collect::<Vec<_>>();
+    let mut removals = reinstalls
+        .iter()
+        .chain(extraneous)
+        .map(|dist| (dist.name(), ChangedDist::Local(dist.clone().into())))
+        .collect::<BTreeMap<_, _>>();
+
+    writeln!(printer.stderr(), "{}", "Installation plan:".bold())?;
+
+    let mut download_size = 0;
+    for (dist, size) in &installs {
+        let size = if let Some(size) = size {
+            download_size += size;
+            format!(" ({})", format_bytes(*size))
+        } else {
+            String::new()
+        };
+        if let Some(previous) = removals.remove(dist.name()) {
+            writeln!(
+                printer.stderr(),
+                " {} {} {} -> {}{}",
+                "~".yellow(),
+                dist.name().bold(),
+                previous.short_specifier().to_string().trim_start().dimmed(),
+                dist.short_specifier().to_string().trim_start().dimmed(),
+                size.dimmed()
+            )?;
+        } else {
+            writeln!(
+                printer.stderr(),
+                " {} {}{}{}",
+                "+".green(),
+                dist.name().bold(),
+                dist.long_specifier().dimmed(),
+                size.dimmed()
+            )?;
+        }
+    }
+    for dist in removals.values() {
+        writeln!(
+            printer.stderr(),
+            " {} {}{}",
+            "-".red(),
+            dist.name().bold(),
+            dist.long_specifier().dimmed()
+        )?;
+    }
+
+    if download_size > 0 {
+        writeln!(
+            printer.stderr(),
+            "Total download size: {}",
+            format_bytes(download_size).bold()
+        )?;
+    }
+
+    if !matches!(show_plan, ShowPlan::Confirm) {
+        return Ok(true);
+    }
+    let term = Term::stderr();
+    if !term.is_term() {
+        return Ok(true);
+    }
+    Ok(uv_console::confirm(
+        "Proceed with installation?",
+        &term,
+        true,
+    )?)
+}
+
+/// Format a byte count for display, e.g., `1.5MiB`.
+fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes}B")
+    } else {
+        let (bytes, unit) = human_readable_bytes(bytes);
+        format!("{bytes:.1}{unit}")
+    }
+}
+
 /// Report any diagnostics on resolved distributions.
 pub(crate) fn diagnose_resolution(
     diagnostics: &[ResolutionDiagnostic],
@@ -1069,6 +1267,23 @@ pub(crate) fn diagnose_resolution(
     Ok(())
 }
 
+/// Report the given resolution diagnostics as errors.
+pub(crate) fn report_resolution_errors<'a>(
+    diagnostics: impl IntoIterator<Item = &'a ResolutionDiagnostic>,
+    printer: Printer,
+) -> Result<(), Error> {
+    for diagnostic in diagnostics {
+        writeln!(
+            printer.stderr(),
+            "{}{} {}",
+            "error".red().bold(),
+            ":".bold(),
+            diagnostic.message().bold()
+        )?;
+    }
+    Ok(())
+}
+
 /// Report any diagnostics on installed distributions in the Python environment.
 pub(crate) fn diagnose_environment(
     resolution: &Resolution,
@@ -1125,4 +1340,7 @@ pub(crate) enum Error {
 
     #[error("The environment is outdated; run `{}` to update the environment", "uv sync".cyan())]
     OutdatedEnvironment(Box<Changelog>),
+
+    #[error("Installation aborted; the environment was not modified")]
+    Aborted,
 }
@@ -33,7 +33,7 @@ use uv_preview::Preview;
 use uv_python::{Interpreter, PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_redacted::DisplaySafeUrl;
 use uv_requirements::{NamedRequirementsResolver, RequirementsSource, RequirementsSpecification};
-use uv_resolver::FlatIndex;
+use uv_resolver::{FlatIndex, WheelTags};
 use uv_scripts::{Pep723Metadata, Pep723Script};
 use uv_settings::PythonInstallMirrors;
 use uv_types::{BuildIsolation, HashStrategy, SourceTreeEditablePolicy};
@@ -120,6 +120,12 @@ pub(crate) async fn add(
             RequirementsSource::PylockToml(_) => {
                 bail!("Adding requirements from a `pylock.toml` is not supported in `uv add`");
             }
+            RequirementsSource::ConstraintsToml(_) => {
+                bail!("Adding requirements from a constraints file is not supported in `uv add`");
+            }
+            RequirementsSource::OverridesToml(_) => {
+                bail!("Adding requirements from an overrides file is not supported in `uv add`");
+            }
             RequirementsSource::Package(_)
             | RequirementsSource::Editable(_)
             | RequirementsSource::RequirementsTxt(_)
@@ -419,7 +425,13 @@ pub(crate) async fn add(
                             .map(Index::url),
                     )
                     .await?;
-                FlatIndex::from_entries(entries, None, &hasher, &settings.resolver.build_options)
+                FlatIndex::from_entries(
+                    entries,
+                    None,
+                    &hasher,
+                    &settings.resolver.build_options,
+                    &WheelTags::default(),
+                )
             };
 
             // Lower the extra build dependencies, if any.
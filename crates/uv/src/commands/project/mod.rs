@@ -13,7 +13,7 @@ use uv_cache_key::cache_digest;
 use uv_client::{BaseClientBuilder, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
     Concurrency, Constraints, DependencyGroupsWithDefaults, DryRun, ExtrasSpecification,
-    GitLfsSetting, Reinstall, TargetTriple, Upgrade,
+    GitLfsSetting, Reinstall, ShowPlan, TargetTriple, Upgrade,
 };
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::{DistributionDatabase, LoweredExtraBuildDependencies, LoweredRequirement};
@@ -38,7 +38,7 @@ use uv_requirements::upgrade::{LockedRequirements, read_lock_requirements};
 use uv_requirements::{NamedRequirementsResolver, RequirementsSpecification};
 use uv_resolver::{
     FlatIndex, Installable, Lock, OptionsBuilder, Preference, PythonRequirement,
-    ResolverEnvironment, ResolverOutput,
+    ResolverEnvironment, ResolverOutput, WheelTags,
 };
 use uv_scripts::Pep723ItemRef;
 use uv_settings::PythonInstallMirrors;
@@ -51,6 +51,7 @@ use uv_workspace::dependency_groups::DependencyGroupError;
 use uv_workspace::pyproject::{ExtraBuildDependency, PyProjectToml};
 use uv_workspace::{RequiresPythonSources, Workspace, WorkspaceCache};
 
+use crate::commands::pip::hooks::InstallHooks;
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
 use crate::commands::pip::operations::{Changelog, Modifications};
 use crate::commands::project::install_target::InstallTarget;
@@ -2138,7 +2139,13 @@ pub(crate) async fn resolve_environment(
         let entries = client
             .fetch_all(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(&tags), &hasher, build_options)
+        FlatIndex::from_entries(
+            entries,
+            Some(&tags),
+            &hasher,
+            build_options,
+            &WheelTags::default(),
+        )
     };
 
     // Lower the extra build dependencies, if any.
@@ -2279,7 +2286,13 @@ pub(crate) async fn sync_environment(
         let entries = client
             .fetch_all(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(tags), &hasher, build_options)
+        FlatIndex::from_entries(
+            entries,
+            Some(tags),
+            &hasher,
+            build_options,
+            &WheelTags::default(),
+        )
     };
 
     // Lower the extra build dependencies, if any.
@@ -2334,7 +2347,9 @@ pub(crate) async fn sync_environment(
         &venv,
         logger,
         installer_metadata,
+        &InstallHooks::default(),
         dry_run,
+        ShowPlan::Disabled,
         printer,
         preview,
     )
@@ -2542,7 +2557,13 @@ pub(crate) async fn update_environment(
         let entries = client
             .fetch_all(index_locations.flat_indexes().map(Index::url))
             .await?;
-        FlatIndex::from_entries(entries, Some(&tags), &hasher, build_options)
+        FlatIndex::from_entries(
+            entries,
+            Some(&tags),
+            &hasher,
+            build_options,
+            &WheelTags::default(),
+        )
     };
 
     // Create a build dispatch.
@@ -2627,7 +2648,9 @@ pub(crate) async fn update_environment(
         &venv,
         install,
         installer_metadata,
+        &InstallHooks::default(),
         dry_run,
+        ShowPlan::Disabled,
         printer,
         preview,
     )
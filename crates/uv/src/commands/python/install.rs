@@ -27,6 +27,7 @@ use uv_python::downloads::{
 use uv_python::managed::{
     ManagedPythonInstallation, ManagedPythonInstallations, PythonMinorVersionLink,
     compare_build_versions, create_link_to_executable, python_executable_dir,
+    replace_link_to_executable,
 };
 use uv_python::{
     ImplementationName, Interpreter, PythonDownloads, PythonInstallationKey,
@@ -1147,8 +1148,24 @@ fn create_bin_links(
                     }
                 }
 
+                // On Windows, a launcher for another managed installation is pointed at this
+                // installation in place, rather than removed and recreated.
+                let retarget = cfg!(windows) && existing.is_some();
+
                 // Replace the existing link
-                if let Err(err) = fs_err::remove_file(&target) {
+                if retarget {
+                    if let Err(err) = replace_link_to_executable(&target, &executable) {
+                        errors.push((
+                            InstallErrorKind::Bin,
+                            installation.key().clone(),
+                            anyhow::anyhow!(
+                                "Failed to update link at `{}`: {err}",
+                                target.simplified_display()
+                            ),
+                        ));
+                        continue;
+                    }
+                } else if let Err(err) = fs_err::remove_file(&target) {
                     errors.push((
                         InstallErrorKind::Bin,
                         installation.key().clone(),
@@ -1170,7 +1187,7 @@ fn create_bin_links(
                         .remove(&target);
                 }
 
-                if let Err(err) = create_link_to_executable(&target, &executable) {
+                if !retarget && let Err(err) = create_link_to_executable(&target, &executable) {
                     errors.push((
                         InstallErrorKind::Bin,
                         installation.key().clone(),
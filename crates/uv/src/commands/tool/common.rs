@@ -175,6 +175,7 @@ pub(crate) fn finalize_tool_install(
     installed_tools: &InstalledTools,
     options: &ToolOptions,
     force: bool,
+    gui_detach: bool,
     python: Option<PythonRequest>,
     requirements: Vec<Requirement>,
     constraints: Vec<Requirement>,
@@ -310,6 +311,15 @@ pub(crate) fn finalize_tool_install(
                 fs_err::copy(src, &target).context("Failed to install entrypoint")?;
             }
 
+            // Console executables are left attached, since their exit code is forwarded.
+            #[cfg(windows)]
+            if gui_detach
+                && uv_trampoline_builder::Launcher::detach_gui(&target)
+                    .context("Failed to detach entrypoint")?
+            {
+                debug!("Detached GUI executable: `{name}`");
+            }
+
             let tool_entry = ToolEntrypoint::new(&name, target, package.to_string());
             names.insert(tool_entry.name.clone());
             installed_entrypoints.push(tool_entry);
@@ -339,6 +349,7 @@ pub(crate) fn finalize_tool_install(
         python,
         installed_entrypoints,
         options.clone(),
+        gui_detach,
     );
     installed_tools.add_tool_receipt(name, tool)?;
 
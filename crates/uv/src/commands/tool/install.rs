@@ -67,6 +67,7 @@ pub(crate) async fn install(
     python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
     force: bool,
+    gui_detach: bool,
     options: ResolverInstallerOptions,
     settings: ResolverInstallerSettings,
     client_builder: BaseClientBuilder<'_>,
@@ -476,6 +477,7 @@ pub(crate) async fn install(
                 && constraints == tool_receipt.constraints()
                 && overrides == tool_receipt.overrides()
                 && build_constraints == tool_receipt.build_constraints()
+                && gui_detach == tool_receipt.gui_detach()
             {
                 let ResolverInstallerSettings {
                     resolver:
@@ -759,6 +761,7 @@ pub(crate) async fn install(
         &installed_tools,
         &options,
         force || invalid_tool_receipt,
+        gui_detach,
         // Only persist the Python request if it was explicitly provided
         if explicit_python_request {
             python_request
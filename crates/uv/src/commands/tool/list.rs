@@ -17,6 +17,7 @@ use uv_normalize::PackageName;
 use uv_python::LenientImplementationName;
 use uv_settings::{Combine, ResolverInstallerOptions};
 use uv_tool::InstalledTools;
+use uv_trampoline_builder::Launcher;
 use uv_warnings::warn_user;
 
 use crate::commands::ExitStatus;
@@ -288,7 +289,18 @@ pub(crate) async fn list(
         // Output tool entrypoints
         for entrypoint in tool.entrypoints() {
             if show_paths {
-                writeln!(printer.stdout(), "- {}", entrypoint.to_string().cyan())?;
+                // Flag launchers whose interpreter no longer exists.
+                let dangling = Launcher::dangling_python_path(&entrypoint.install_path)
+                    .map(|python| {
+                        format!(" [missing interpreter: {}]", python.simplified_display())
+                    })
+                    .unwrap_or_default();
+                writeln!(
+                    printer.stdout(),
+                    "- {}{}",
+                    entrypoint.to_string().cyan(),
+                    dangling.red()
+                )?;
             } else {
                 writeln!(printer.stdout(), "- {}", entrypoint.name)?;
             }
@@ -108,6 +108,7 @@ pub(crate) async fn run(
     show_resolution: bool,
     lfs: GitLfsSetting,
     python: Option<String>,
+    python_path: Option<PathBuf>,
     python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
     options: ResolverInstallerOptions,
@@ -294,6 +295,7 @@ pub(crate) async fn run(
         build_constraints,
         show_resolution,
         python.as_deref(),
+        python_path.as_deref(),
         python_platform,
         install_mirrors,
         options,
@@ -727,6 +729,7 @@ async fn get_or_create_environment(
     build_constraints: &[RequirementsSource],
     show_resolution: bool,
     python: Option<&str>,
+    python_path: Option<&Path>,
     python_platform: Option<TargetTriple>,
     install_mirrors: PythonInstallMirrors,
     options: ResolverInstallerOptions,
@@ -746,7 +749,11 @@ async fn get_or_create_environment(
     let reporter = PythonDownloadReporter::single(printer);
 
     // Determine explicit Python version requests
-    let explicit_python_request = python.map(PythonRequest::parse);
+    let explicit_python_request = match python_path {
+        // e.g., `uvx --python-path /path/to/python3.14t ...`
+        Some(python_path) => Some(PythonRequest::File(CWD.join(python_path))),
+        None => python.map(PythonRequest::parse),
+    };
     let tool_python_request = match request {
         ToolRequest::Python { request, .. } => Some(request.clone()),
         ToolRequest::Package { .. } => None,
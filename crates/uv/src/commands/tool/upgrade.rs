@@ -448,6 +448,7 @@ async fn upgrade_tool(
             installed_tools,
             &ToolOptions::from(options),
             true,
+            existing_tool_receipt.gui_detach(),
             existing_tool_receipt.python().to_owned(),
             existing_tool_receipt.requirements().to_vec(),
             existing_tool_receipt.constraints().to_vec(),
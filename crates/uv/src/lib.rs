@@ -29,9 +29,9 @@ use uv_cache_info::Timestamp;
 use uv_cli::SelfUpdateArgs;
 use uv_cli::{
     AuthCommand, AuthHelperCommand, AuthNamespace, BuildBackendCommand, CacheCommand,
-    CacheNamespace, Cli, Commands, PipCommand, PipNamespace, ProjectCommand, PythonCommand,
-    PythonNamespace, SelfCommand, SelfNamespace, ToolCommand, ToolNamespace, TopLevelArgs,
-    WorkspaceCommand, WorkspaceNamespace, compat::CompatArgs,
+    CacheNamespace, Cli, Commands, ConstraintFormat, PipCommand, PipNamespace, ProjectCommand,
+    PythonCommand, PythonNamespace, SelfCommand, SelfNamespace, ToolCommand, ToolNamespace,
+    TopLevelArgs, WorkspaceCommand, WorkspaceNamespace, compat::CompatArgs,
 };
 use uv_client::BaseClientBuilder;
 use uv_configuration::min_stack_size;
@@ -630,6 +630,11 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 .overrides
                 .into_iter()
                 .map(RequirementsSource::from_overrides_txt)
+                .chain(
+                    args.dependency_overrides_file
+                        .into_iter()
+                        .map(|path| Ok(RequirementsSource::from_overrides_toml(path))),
+                )
                 .collect::<Result<Vec<_>, _>>()?;
             let excludes = args
                 .excludes
@@ -660,6 +665,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.settings.extras,
                 groups,
                 args.settings.output_file.as_deref(),
+                args.settings.constraint_output.as_deref(),
                 args.format,
                 args.settings.resolution,
                 args.settings.prerelease,
@@ -673,11 +679,21 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 !args.settings.no_annotate,
                 !args.settings.no_header,
                 args.settings.custom_compile_command,
+                args.settings.header_comment,
                 args.settings.emit_index_url,
                 args.settings.emit_find_links,
                 args.settings.emit_build_options,
                 args.settings.emit_marker_expression,
                 args.settings.emit_index_annotation,
+                args.settings.emit_direct_only,
+                args.settings.emit_package_urls,
+                args.settings.emit_license,
+                args.settings.emit_extras,
+                args.settings.emit_tree,
+                args.settings.group_by_extra,
+                args.settings.strict_extras,
+                args.check_url.as_ref(),
+                args.fail_on_license.as_ref(),
                 args.settings.index_locations,
                 args.settings.index_strategy,
                 args.settings.torch_backend,
@@ -692,6 +708,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.settings.build_options,
                 args.settings.install_mirrors,
                 args.settings.python_version,
+                args.python_requires,
                 args.settings.python_platform,
                 globals.python_downloads,
                 args.settings.universal,
@@ -808,7 +825,10 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
             show_settings!(args);
 
             let mut requirements = Vec::with_capacity(
-                args.package.len() + args.editables.len() + args.requirements.len(),
+                args.package.len()
+                    + args.editables.len()
+                    + args.with_editables.len()
+                    + args.requirements.len(),
             );
             for package in args.package {
                 requirements.push(RequirementsSource::from_package_argument(&package)?);
@@ -822,15 +842,36 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                     .map(RequirementsSource::from_requirements_file)
                     .collect::<Result<Vec<_>, _>>()?,
             );
+
+            // Install each `--with-editable` package alongside the requirements, and override
+            // any other requirement for the same package with it.
+            let with_editables = args
+                .with_editables
+                .iter()
+                .map(|package| RequirementsSource::from_editable(package))
+                .collect::<Result<Vec<_>, _>>()?;
+            requirements.extend(with_editables.iter().cloned());
             let constraints = args
                 .constraints
                 .into_iter()
-                .map(RequirementsSource::from_constraints_txt)
+                .map(|path| match args.constraint_format {
+                    Some(ConstraintFormat::Toml) => RequirementsSource::from_constraints_toml(path),
+                    Some(ConstraintFormat::RequirementsTxt) => {
+                        match RequirementsSource::from_constraints_txt(path)? {
+                            RequirementsSource::ConstraintsToml(path) => {
+                                Ok(RequirementsSource::RequirementsTxt(path))
+                            }
+                            source => Ok(source),
+                        }
+                    }
+                    None => RequirementsSource::from_constraints_txt(path),
+                })
                 .collect::<Result<Vec<_>, _>>()?;
             let overrides = args
                 .overrides
                 .into_iter()
                 .map(RequirementsSource::from_overrides_txt)
+                .chain(with_editables.into_iter().map(Ok))
                 .collect::<Result<Vec<_>, _>>()?;
             let excludes = args
                 .excludes
@@ -901,16 +942,38 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                     .combine(Refresh::from(args.settings.upgrade.clone())),
             );
 
-            Box::pin(commands::pip_install(
+            // Refresh cached metadata, but not cached distributions.
+            let cache = if args.no_cache_metadata {
+                cache.with_metadata_refresh(Timestamp::now())
+            } else {
+                cache
+            };
+
+            // Download wheels into a temporary directory, but continue to cache metadata.
+            let cache = if args.no_wheel_cache {
+                cache.with_temporary_wheels()?
+            } else {
+                cache
+            };
+
+            // Retain the index locations to persist their credentials once the install succeeds.
+            let save_credentials = args
+                .save_credentials
+                .map(|target| (target, args.settings.index_locations.clone()));
+
+            let status = Box::pin(commands::pip_install(
                 &requirements,
                 &constraints,
                 &overrides,
                 &excludes,
                 &build_constraints,
+                args.constraints_from_env,
+                args.constraint_strict,
                 args.constraints_from_workspace,
                 args.overrides_from_workspace,
                 args.excludes_from_workspace,
                 args.build_constraints_from_workspace,
+                &args.build_isolation_packages,
                 &args.settings.extras,
                 &groups,
                 args.settings.resolution,
@@ -937,6 +1000,12 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.modifications,
                 args.settings.python_version,
                 args.settings.python_platform,
+                args.platform_override,
+                args.wheel_tags,
+                args.record.as_deref(),
+                args.clean_env,
+                args.hooks,
+                args.post_install_check,
                 globals.python_downloads,
                 args.settings.install_mirrors,
                 args.settings.strict,
@@ -952,10 +1021,19 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 cache,
                 workspace_cache,
                 args.dry_run,
+                args.show_plan,
                 printer,
                 globals.preview,
             ))
-            .await
+            .await?;
+
+            if let Some((target, index_locations)) = save_credentials
+                && matches!(status, ExitStatus::Success)
+            {
+                commands::auth_save(&index_locations, target, printer, globals.preview).await?;
+            }
+
+            Ok(status)
         }
         Commands::Pip(PipNamespace {
             command: PipCommand::Uninstall(args),
@@ -1156,6 +1234,12 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Dir,
         }) => commands::cache_dir(&cache, printer),
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Path(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_path(cache, args.category, args.create, printer).await
+        }
         Commands::Cache(CacheNamespace {
             command: CacheCommand::Size(args),
         }) => commands::cache_size(&cache, args.human, printer, globals.preview),
@@ -1470,6 +1554,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.show_resolution || globals.verbose > 0,
                 args.lfs,
                 args.python,
+                args.python_path,
                 args.python_platform,
                 args.install_mirrors,
                 args.options,
@@ -1575,6 +1660,7 @@ async fn run(cli: Cli) -> Result<ExitStatus> {
                 args.python_platform,
                 args.install_mirrors,
                 args.force,
+                args.gui_detach,
                 args.options,
                 args.settings,
                 client_builder.subcommand(vec!["tool".to_owned(), "install".to_owned()]),
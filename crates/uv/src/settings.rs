@@ -14,13 +14,14 @@ use uv_auth::Service;
 use uv_cache::{CacheArgs, Refresh};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
-    AddArgs, AuditArgs, AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs, ColorChoice, ExternalCommand,
-    GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe, MetadataArgs, PipCheckArgs, PipCompileArgs,
-    PipFreezeArgs, PipInstallArgs, PipListArgs, PipShowArgs, PipSyncArgs, PipTreeArgs,
-    PipUninstallArgs, PythonFindArgs, PythonInstallArgs, PythonListArgs, PythonListFormat,
-    PythonPinArgs, PythonUninstallArgs, PythonUpgradeArgs, RemoveArgs, RunArgs, SyncArgs,
-    SyncFormat, ToolDirArgs, ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs,
-    TreeArgs, VenvArgs, VersionArgs, VersionBumpSpec, VersionFormat,
+    AddArgs, AuditArgs, AuthLoginArgs, AuthLogoutArgs, AuthTokenArgs, ColorChoice,
+    ConstraintFormat, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe,
+    MetadataArgs, PipCheckArgs, PipCompileArgs, PipFreezeArgs, PipInstallArgs, PipListArgs,
+    PipShowArgs, PipSyncArgs, PipTreeArgs, PipUninstallArgs, PythonFindArgs, PythonInstallArgs,
+    PythonListArgs, PythonListFormat, PythonPinArgs, PythonUninstallArgs, PythonUpgradeArgs,
+    RemoveArgs, RunArgs, SaveCredentials, SyncArgs, SyncFormat, ToolDirArgs, ToolInstallArgs,
+    ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs, VersionArgs, VersionBumpSpec,
+    VersionFormat,
 };
 use uv_cli::{
     AuthorFrom, BuildArgs, ExportArgs, FormatArgs, PublishArgs, PythonDirArgs,
@@ -35,7 +36,7 @@ use uv_configuration::{
     BuildIsolation, BuildOptions, Concurrency, DependencyGroups, DryRun, EditableMode, EnvFile,
     ExportFormat, ExtrasSpecification, GitLfsSetting, HashCheckingMode, IndexStrategy,
     InstallOptions, KeyringProviderType, NoBinary, NoBuild, NoSources, PipCompileFormat,
-    ProjectBuildBackend, ProxyUrl, Reinstall, RequiredVersion, TargetTriple, TrustedHost,
+    ProjectBuildBackend, ProxyUrl, Reinstall, RequiredVersion, ShowPlan, TargetTriple, TrustedHost,
     TrustedPublishing, Upgrade, VersionControlSystem,
 };
 use uv_distribution_types::{
@@ -45,13 +46,14 @@ use uv_distribution_types::{
 use uv_install_wheel::LinkMode;
 use uv_normalize::{ExtraName, PackageName, PipGroupName};
 use uv_pep508::{MarkerTree, RequirementOrigin};
+use uv_platform_tags::PlatformTag;
 use uv_preview::Preview;
 use uv_pypi_types::SupportedEnvironments;
 use uv_python::{Prefix, PythonDownloads, PythonPreference, PythonVersion, Target};
 use uv_redacted::DisplaySafeUrl;
 use uv_resolver::{
     AnnotationStyle, DependencyMode, ExcludeNewer, ExcludeNewerPackage, ForkStrategy,
-    PrereleaseMode, ResolutionMode,
+    PrereleaseMode, ResolutionMode, WheelTags,
 };
 use uv_settings::{
     Combine, EnvironmentOptions, FilesystemOptions, Options, PipOptions, PublishOptions,
@@ -64,6 +66,8 @@ use uv_workspace::pyproject::{DependencyType, ExtraBuildDependencies};
 use uv_workspace::pyproject_mut::AddBoundsKind;
 
 use crate::commands::ToolRunCommand;
+use crate::commands::pip::hooks::InstallHooks;
+use crate::commands::pip::import_check::PostInstallCheck;
 use crate::commands::{InitKind, InitProjectKind, pip::operations::Modifications};
 
 /// The default publish URL.
@@ -739,6 +743,7 @@ pub(crate) struct ToolRunSettings {
     pub(crate) show_resolution: bool,
     pub(crate) lfs: GitLfsSetting,
     pub(crate) python: Option<String>,
+    pub(crate) python_path: Option<PathBuf>,
     pub(crate) python_platform: Option<TargetTriple>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
@@ -774,6 +779,7 @@ impl ToolRunSettings {
             refresh,
             lfs,
             python,
+            python_path,
             python_platform,
             torch_backend,
             generate_shell_completion: _,
@@ -861,6 +867,7 @@ impl ToolRunSettings {
             show_resolution,
             lfs,
             python: python.and_then(Maybe::into_option),
+            python_path,
             python_platform,
             refresh: Refresh::from(refresh),
             settings,
@@ -894,6 +901,7 @@ pub(crate) struct ToolInstallSettings {
     pub(crate) options: ResolverInstallerOptions,
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) force: bool,
+    pub(crate) gui_detach: bool,
     pub(crate) editable: bool,
     pub(crate) install_mirrors: PythonInstallMirrors,
 }
@@ -918,6 +926,7 @@ impl ToolInstallSettings {
             excludes,
             build_constraints,
             lfs,
+            gui_detach,
             installer,
             force,
             build,
@@ -986,6 +995,7 @@ impl ToolInstallSettings {
             python: python.and_then(Maybe::into_option),
             python_platform,
             force,
+            gui_detach,
             editable,
             refresh: Refresh::from(refresh),
             options,
@@ -2696,6 +2706,7 @@ pub(crate) struct PipCompileSettings {
     pub(crate) src_file: Vec<PathBuf>,
     pub(crate) constraints: Vec<PathBuf>,
     pub(crate) overrides: Vec<PathBuf>,
+    pub(crate) dependency_overrides_file: Vec<PathBuf>,
     pub(crate) excludes: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
@@ -2703,6 +2714,9 @@ pub(crate) struct PipCompileSettings {
     pub(crate) excludes_from_workspace: Vec<PackageName>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
     pub(crate) environments: SupportedEnvironments,
+    pub(crate) check_url: Option<IndexUrl>,
+    pub(crate) fail_on_license: Option<spdx::Expression>,
+    pub(crate) python_requires: Option<PythonVersion>,
     pub(crate) refresh: Refresh,
     pub(crate) settings: PipSettings,
 }
@@ -2710,7 +2724,7 @@ pub(crate) struct PipCompileSettings {
 impl PipCompileSettings {
     /// Resolve the [`PipCompileSettings`] from the CLI and filesystem configuration.
     pub(crate) fn resolve(
-        args: PipCompileArgs,
+        args: Box<PipCompileArgs>,
         filesystem: Option<FilesystemOptions>,
         environment: EnvironmentOptions,
     ) -> Self {
@@ -2718,6 +2732,7 @@ impl PipCompileSettings {
             src_file,
             constraints,
             overrides,
+            dependency_overrides_file,
             excludes,
             extra,
             all_extras,
@@ -2728,6 +2743,10 @@ impl PipCompileSettings {
             deps,
             group,
             output_file,
+            constraint_output,
+            check_url,
+            fail_on_license,
+            python_requires,
             format,
             no_strip_extras,
             strip_extras,
@@ -2739,6 +2758,7 @@ impl PipCompileSettings {
             header,
             annotation_style,
             custom_compile_command,
+            header_comment,
             resolver,
             python,
             system,
@@ -2764,9 +2784,23 @@ impl PipCompileSettings {
             no_emit_marker_expression,
             emit_index_annotation,
             no_emit_index_annotation,
+            emit_direct_only,
+            no_emit_direct_only,
+            emit_package_urls,
+            no_emit_package_urls,
+            emit_license,
+            no_emit_license,
+            emit_extras,
+            no_emit_extras,
+            emit_tree,
+            no_emit_tree,
+            group_by_extra,
+            no_group_by_extra,
+            strict_extras,
+            no_strict_extras,
             torch_backend,
             compat_args: _,
-        } = args;
+        } = *args;
 
         let constraints_from_workspace = if let Some(configuration) = &filesystem {
             configuration
@@ -2840,6 +2874,7 @@ impl PipCompileSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            dependency_overrides_file,
             excludes: excludes
                 .into_iter()
                 .filter_map(Maybe::into_option)
@@ -2849,6 +2884,9 @@ impl PipCompileSettings {
             excludes_from_workspace,
             build_constraints_from_workspace,
             environments,
+            check_url,
+            fail_on_license,
+            python_requires,
             refresh: Refresh::from(refresh),
             settings: PipSettings::combine(
                 PipOptions {
@@ -2862,11 +2900,13 @@ impl PipCompileSettings {
                     no_deps: flag(no_deps, deps, "deps"),
                     group: Some(group),
                     output_file,
+                    constraint_output,
                     no_strip_extras: flag(no_strip_extras, strip_extras, "strip-extras"),
                     no_strip_markers: flag(no_strip_markers, strip_markers, "strip-markers"),
                     no_annotate: flag(no_annotate, annotate, "annotate"),
                     no_header: flag(no_header, header, "header"),
                     custom_compile_command,
+                    header_comment,
                     generate_hashes: flag(generate_hashes, no_generate_hashes, "generate-hashes"),
                     python_version,
                     python_platform,
@@ -2889,6 +2929,21 @@ impl PipCompileSettings {
                         no_emit_index_annotation,
                         "emit-index-annotation",
                     ),
+                    emit_direct_only: flag(
+                        emit_direct_only,
+                        no_emit_direct_only,
+                        "emit-direct-only",
+                    ),
+                    emit_package_urls: flag(
+                        emit_package_urls,
+                        no_emit_package_urls,
+                        "emit-package-urls",
+                    ),
+                    emit_license: flag(emit_license, no_emit_license, "emit-license"),
+                    emit_extras: flag(emit_extras, no_emit_extras, "emit-extras"),
+                    emit_tree: flag(emit_tree, no_emit_tree, "emit-tree"),
+                    group_by_extra: flag(group_by_extra, no_group_by_extra, "group-by-extra"),
+                    strict_extras: flag(strict_extras, no_strict_extras, "strict-extras"),
                     annotation_style,
                     torch_backend,
                     ..PipOptions::from(resolver)
@@ -3009,24 +3064,39 @@ pub(crate) struct PipInstallSettings {
     pub(crate) package: Vec<String>,
     pub(crate) requirements: Vec<PathBuf>,
     pub(crate) editables: Vec<String>,
+    pub(crate) with_editables: Vec<String>,
     pub(crate) constraints: Vec<PathBuf>,
+    pub(crate) constraint_format: Option<ConstraintFormat>,
     pub(crate) overrides: Vec<PathBuf>,
     pub(crate) excludes: Vec<PathBuf>,
     pub(crate) build_constraints: Vec<PathBuf>,
+    pub(crate) build_isolation_packages: Vec<Requirement>,
     pub(crate) dry_run: DryRun,
+    pub(crate) show_plan: ShowPlan,
+    pub(crate) clean_env: bool,
+    pub(crate) record: Option<PathBuf>,
+    pub(crate) hooks: InstallHooks,
+    pub(crate) post_install_check: PostInstallCheck,
+    pub(crate) save_credentials: Option<SaveCredentials>,
+    pub(crate) platform_override: Option<PlatformTag>,
+    pub(crate) wheel_tags: WheelTags,
+    pub(crate) constraints_from_env: bool,
+    pub(crate) constraint_strict: bool,
     pub(crate) constraints_from_workspace: Vec<Requirement>,
     pub(crate) overrides_from_workspace: Vec<Requirement>,
     pub(crate) excludes_from_workspace: Vec<PackageName>,
     pub(crate) build_constraints_from_workspace: Vec<Requirement>,
     pub(crate) modifications: Modifications,
     pub(crate) refresh: Refresh,
+    pub(crate) no_cache_metadata: bool,
+    pub(crate) no_wheel_cache: bool,
     pub(crate) settings: PipSettings,
 }
 
 impl PipInstallSettings {
     /// Resolve the [`PipInstallSettings`] from the CLI and filesystem configuration.
     pub(crate) fn resolve(
-        args: PipInstallArgs,
+        args: Box<PipInstallArgs>,
         filesystem: Option<FilesystemOptions>,
         environment: EnvironmentOptions,
     ) -> Self {
@@ -3034,7 +3104,11 @@ impl PipInstallSettings {
             package,
             requirements,
             editable,
+            with_editable,
             constraints,
+            constraint_format,
+            constraints_from_env,
+            constraint_strict,
             overrides,
             excludes,
             build_constraints,
@@ -3043,6 +3117,8 @@ impl PipInstallSettings {
             no_all_extras,
             installer,
             refresh,
+            no_cache_metadata,
+            no_wheel_cache,
             no_deps,
             deps,
             group,
@@ -3061,16 +3137,28 @@ impl PipInstallSettings {
             build,
             no_binary,
             only_binary,
+            build_isolation_packages,
             python_version,
             python_platform,
+            platform_override,
+            wheel_tag,
             inexact,
             exact,
             strict,
             no_strict,
             dry_run,
+            show_plan,
+            yes,
+            clean_env,
+            record,
+            hooks_pre,
+            hooks_post,
+            post_install_check,
+            strict_post_install_check,
+            save_credentials,
             torch_backend,
             compat_args: _,
-        } = args;
+        } = *args;
 
         let constraints_from_workspace = if let Some(configuration) = &filesystem {
             configuration
@@ -3127,10 +3215,12 @@ impl PipInstallSettings {
             package,
             requirements,
             editables: editable,
+            with_editables: with_editable,
             constraints: constraints
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            constraint_format,
             overrides: overrides
                 .into_iter()
                 .filter_map(Maybe::into_option)
@@ -3143,7 +3233,24 @@ impl PipInstallSettings {
                 .into_iter()
                 .filter_map(Maybe::into_option)
                 .collect(),
+            build_isolation_packages: build_isolation_packages
+                .into_iter()
+                .map(Requirement::from)
+                .collect(),
             dry_run: DryRun::from_args(dry_run),
+            show_plan: ShowPlan::from_args(show_plan, yes),
+            clean_env,
+            record,
+            hooks: InstallHooks::new(hooks_pre, hooks_post),
+            post_install_check: PostInstallCheck::from_args(
+                post_install_check,
+                strict_post_install_check,
+            ),
+            save_credentials,
+            platform_override,
+            wheel_tags: wheel_tag.into_iter().collect(),
+            constraints_from_env,
+            constraint_strict,
             constraints_from_workspace,
             overrides_from_workspace,
             excludes_from_workspace,
@@ -3154,6 +3261,8 @@ impl PipInstallSettings {
                 Modifications::Sufficient
             },
             refresh: Refresh::from(refresh),
+            no_cache_metadata,
+            no_wheel_cache,
             settings: PipSettings::combine(
                 PipOptions {
                     python: python.and_then(Maybe::into_option),
@@ -3912,11 +4021,13 @@ pub(crate) struct PipSettings {
     pub(crate) fork_strategy: ForkStrategy,
     pub(crate) dependency_metadata: DependencyMetadata,
     pub(crate) output_file: Option<PathBuf>,
+    pub(crate) constraint_output: Option<PathBuf>,
     pub(crate) no_strip_extras: bool,
     pub(crate) no_strip_markers: bool,
     pub(crate) no_annotate: bool,
     pub(crate) no_header: bool,
     pub(crate) custom_compile_command: Option<String>,
+    pub(crate) header_comment: Option<String>,
     pub(crate) generate_hashes: bool,
     pub(crate) config_setting: ConfigSettings,
     pub(crate) config_settings_package: PackageConfigSettings,
@@ -3930,6 +4041,13 @@ pub(crate) struct PipSettings {
     pub(crate) emit_build_options: bool,
     pub(crate) emit_marker_expression: bool,
     pub(crate) emit_index_annotation: bool,
+    pub(crate) emit_direct_only: bool,
+    pub(crate) emit_package_urls: bool,
+    pub(crate) emit_license: bool,
+    pub(crate) emit_extras: bool,
+    pub(crate) emit_tree: bool,
+    pub(crate) group_by_extra: bool,
+    pub(crate) strict_extras: bool,
     pub(crate) annotation_style: AnnotationStyle,
     pub(crate) link_mode: LinkMode,
     pub(crate) compile_bytecode: bool,
@@ -3988,11 +4106,13 @@ impl PipSettings {
             fork_strategy,
             dependency_metadata,
             output_file,
+            constraint_output,
             no_strip_extras,
             no_strip_markers,
             no_annotate,
             no_header,
             custom_compile_command,
+            header_comment,
             generate_hashes,
             config_settings,
             config_settings_package,
@@ -4006,6 +4126,13 @@ impl PipSettings {
             emit_build_options,
             emit_marker_expression,
             emit_index_annotation,
+            emit_direct_only,
+            emit_package_urls,
+            emit_license,
+            emit_extras,
+            emit_tree,
+            group_by_extra,
+            strict_extras,
             annotation_style,
             link_mode,
             compile_bytecode,
@@ -4145,6 +4272,7 @@ impl PipSettings {
                     .unwrap_or_default(),
             ),
             output_file: args.output_file.combine(output_file),
+            constraint_output: args.constraint_output.combine(constraint_output),
             no_strip_extras: args
                 .no_strip_extras
                 .combine(no_strip_extras)
@@ -4156,6 +4284,7 @@ impl PipSettings {
             no_annotate: args.no_annotate.combine(no_annotate).unwrap_or_default(),
             no_header: args.no_header.combine(no_header).unwrap_or_default(),
             custom_compile_command: args.custom_compile_command.combine(custom_compile_command),
+            header_comment: args.header_comment.combine(header_comment),
             annotation_style: args
                 .annotation_style
                 .combine(annotation_style)
@@ -4233,6 +4362,25 @@ impl PipSettings {
                 .emit_index_annotation
                 .combine(emit_index_annotation)
                 .unwrap_or_default(),
+            emit_direct_only: args
+                .emit_direct_only
+                .combine(emit_direct_only)
+                .unwrap_or_default(),
+            emit_package_urls: args
+                .emit_package_urls
+                .combine(emit_package_urls)
+                .unwrap_or_default(),
+            emit_license: args.emit_license.combine(emit_license).unwrap_or_default(),
+            emit_extras: args.emit_extras.combine(emit_extras).unwrap_or_default(),
+            emit_tree: args.emit_tree.combine(emit_tree).unwrap_or_default(),
+            group_by_extra: args
+                .group_by_extra
+                .combine(group_by_extra)
+                .unwrap_or_default(),
+            strict_extras: args
+                .strict_extras
+                .combine(strict_extras)
+                .unwrap_or_default(),
             link_mode: args.link_mode.combine(link_mode).unwrap_or_default(),
             hash_checking: HashCheckingMode::from_args(
                 args.require_hashes.combine(require_hashes),
@@ -0,0 +1,62 @@
+use uv_test::uv_snapshot;
+
+/// Show the path to a cache subdirectory, without creating it.
+#[test]
+fn cache_path() {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    uv_snapshot!(context.filters(), context.cache_path().arg("sdists"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [CACHE_DIR]/sdists-v9
+
+    ----- stderr -----
+    ");
+
+    uv_snapshot!(context.filters(), context.cache_path().arg("simple"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [CACHE_DIR]/simple-v21
+
+    ----- stderr -----
+    ");
+
+    assert!(!context.cache_dir.join("sdists-v9").exists());
+}
+
+/// Create the cache subdirectory with `--create`.
+#[test]
+fn cache_path_create() {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    uv_snapshot!(context.filters(), context.cache_path().arg("wheels").arg("--create"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [CACHE_DIR]/wheels-v6
+
+    ----- stderr -----
+    ");
+
+    assert!(context.cache_dir.join("wheels-v6").is_dir());
+}
+
+/// Reject unknown cache subdirectories.
+#[test]
+fn cache_path_unknown() {
+    let context = uv_test::test_context_with_versions!(&[]);
+
+    uv_snapshot!(context.filters(), context.cache_path().arg("archives"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'archives' for '<CATEGORY>'
+      [possible values: wheels, sdists, simple, git, interpreter]
+
+    For more information, try '--help'.
+    ");
+}
@@ -2002,6 +2002,48 @@ fn compile_python_37() -> Result<()> {
     Ok(())
 }
 
+/// Resolve Black at Python 3.10 with `--python-requires`, such that the dependencies marked
+/// `python_version < "3.11"` are included, even though the interpreter is Python 3.12.
+#[test]
+fn compile_python_requires() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("black==23.10.1")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--python-requires")
+            .arg("3.10"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --python-requires 3.10
+    black==23.10.1
+        # via -r requirements.in
+    click==8.1.7
+        # via black
+    mypy-extensions==1.0.0
+        # via black
+    packaging==24.0
+        # via black
+    pathspec==0.12.1
+        # via black
+    platformdirs==4.2.0
+        # via black
+    tomli==2.0.1
+        # via black
+    typing-extensions==4.10.0
+        # via black
+
+    ----- stderr -----
+    Resolved 8 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
 /// Resolve a source distribution with `--resolution=lowest-direct`, to ensure that the build
 /// requirements aren't resolved at their lowest compatible version.
 #[test]
@@ -4284,6 +4326,75 @@ fn override_dependency_from_specific_uv_toml() -> Result<()> {
     Ok(())
 }
 
+/// Check that `--dependency-overrides-file` reads `override-dependencies` from a TOML file.
+#[test]
+fn override_dependency_from_toml_file() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask==3.0.0")?;
+
+    let overrides_toml = context.temp_dir.child("overrides.toml");
+    overrides_toml.write_str(
+        r#"
+        override-dependencies = [
+          "werkzeug==2.3.0"
+        ]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--dependency-overrides-file")
+            .arg("overrides.toml"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --dependency-overrides-file overrides.toml
+    blinker==1.7.0
+        # via flask
+    click==8.1.7
+        # via flask
+    flask==3.0.0
+        # via -r requirements.in
+    itsdangerous==2.1.2
+        # via flask
+    jinja2==3.1.3
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    werkzeug==2.3.0
+        # via
+        #   --override overrides.toml
+        #   flask
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    "
+    );
+
+    // A file without `override-dependencies` is rejected.
+    overrides_toml.write_str("constraint-dependencies = []")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--dependency-overrides-file")
+            .arg("overrides.toml"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `overrides.toml`
+      Caused by: Missing `override-dependencies` array
+    "
+    );
+
+    Ok(())
+}
+
 /// Black==23.10.1 depends on tomli>=1.1.0 for Python versions below 3.11. Demonstrate that we can
 /// override it with a multi-line override.
 #[test]
@@ -6275,57 +6386,403 @@ fn custom_compile_command() -> Result<()> {
 
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in")
-            .arg("--custom-compile-command")
-            .arg("./custom-uv-compile.sh"), @"
-    success: true
-    exit_code: 0
+            .arg("--custom-compile-command")
+            .arg("./custom-uv-compile.sh"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    ./custom-uv-compile.sh
+    black==23.10.1
+        # via -r requirements.in
+    click==8.1.7
+        # via black
+    mypy-extensions==1.0.0
+        # via black
+    packaging==24.0
+        # via black
+    pathspec==0.12.1
+        # via black
+    platformdirs==4.2.0
+        # via black
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "
+    );
+
+    // with env var
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .env(EnvVars::UV_CUSTOM_COMPILE_COMMAND, "./custom-uv-compile.sh"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    ./custom-uv-compile.sh
+    black==23.10.1
+        # via -r requirements.in
+    click==8.1.7
+        # via black
+    mypy-extensions==1.0.0
+        # via black
+    packaging==24.0
+        # via black
+    pathspec==0.12.1
+        # via black
+    platformdirs==4.2.0
+        # via black
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+/// Include a custom comment block before the header.
+#[test]
+fn header_comment() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("tqdm")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("test").join("links"))
+            .arg("--header-comment")
+            .arg(r"Copyright (c) Example Corp.\n\nDo not edit."), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # Copyright (c) Example Corp.
+    #
+    # Do not edit.
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-index
+    tqdm==1000.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "
+    );
+
+    // Read the comment block from a file, and include it even without the header.
+    let header = context.temp_dir.child("header.txt");
+    header.write_str(indoc! {r"
+        # SPDX-License-Identifier: MIT
+        Generated by `make requirements`.
+    "})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("test").join("links"))
+            .arg("--no-header")
+            .arg("--header-comment")
+            .arg("@header.txt"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # SPDX-License-Identifier: MIT
+    # Generated by `make requirements`.
+    tqdm==1000.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+/// Write the resolved versions to a separate constraints file via `--constraint-output`.
+#[test]
+fn constraint_output() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("tqdm")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("test").join("links"))
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--constraint-output")
+            .arg("constraints.txt"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-index --output-file requirements.txt --constraint-output constraints.txt
+    tqdm==1000.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "
+    );
+
+    // The constraints file includes the pins, but omits the annotations.
+    let constraints_txt = context.read("constraints.txt");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        insta::assert_snapshot!(constraints_txt, @"
+        # This file was autogenerated by uv via the following command:
+        #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-index --output-file requirements.txt --constraint-output constraints.txt
+        tqdm==1000.0.0
+        ");
+    });
+
+    Ok(())
+}
+
+/// Write the dependency tree alongside the output file with `--emit-tree`.
+#[test]
+fn emit_tree() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask==3.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--emit-tree"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt --emit-tree
+    blinker==1.7.0
+        # via flask
+    click==8.1.7
+        # via flask
+    flask==3.0.0
+        # via -r requirements.in
+    itsdangerous==2.1.2
+        # via flask
+    jinja2==3.1.3
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    werkzeug==3.0.1
+        # via flask
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    "
+    );
+
+    // The tree file nests each dependency beneath the packages that require it.
+    let tree_txt = context.read("requirements.tree.txt");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        insta::assert_snapshot!(tree_txt, @"
+        # This file was autogenerated by uv via the following command:
+        #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --output-file requirements.txt --emit-tree
+        flask==3.0.0
+          - blinker [required: >=1.6.2, installed: 1.7.0]
+          - click [required: >=8.1.3, installed: 8.1.7]
+          - itsdangerous [required: >=2.1.2, installed: 2.1.2]
+          - jinja2 [required: >=3.1.2, installed: 3.1.3]
+            - markupsafe [required: >=2.0, installed: 2.1.5]
+          - werkzeug [required: >=3.0.0, installed: 3.0.1]
+            - markupsafe [required: >=2.1.1, installed: 2.1.5]
+        ");
+    });
+
+    // The tree can only be written alongside an output file.
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--emit-tree"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--emit-tree` requires `--output-file`
+    "
+    );
+
+    Ok(())
+}
+
+/// Fail on missing extras with `--strict-extras`, rather than emitting a warning.
+#[test]
+fn strict_extras() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("tqdm[missing]")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("test").join("links")), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-index
+    tqdm==1000.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: The package `tqdm==1000.0.0` does not have an extra named `missing`
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("test").join("links"))
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--strict-extras"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: The package `tqdm==1000.0.0` does not have an extra named `missing`
+    "
+    );
+
+    // No output is written.
+    assert!(!context.temp_dir.child("requirements.txt").exists());
+
+    Ok(())
+}
+
+/// Verify that the pinned packages are available at an index with `--check-url`.
+#[tokio::test]
+async fn check_url() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("tqdm")?;
+
+    // The index only provides an older version of `tqdm`.
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/tqdm/"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"<a href="https://example.com/tqdm-999.0.0.tar.gz">tqdm-999.0.0.tar.gz</a>"#,
+            "text/html",
+        ))
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("test").join("links"))
+            .arg("--output-file")
+            .arg("requirements.txt")
+            .arg("--check-url")
+            .arg(server.uri()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: `tqdm==1000.0.0` is not available at: http://[LOCALHOST]/
+    ");
+
+    // No output is written.
+    assert!(!context.temp_dir.child("requirements.txt").exists());
+
+    // The index provides the pinned version, but it's yanked.
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/tqdm/"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"<a href="https://example.com/tqdm-1000.0.0-py3-none-any.whl" data-yanked="">tqdm-1000.0.0-py3-none-any.whl</a>"#,
+            "text/html",
+        ))
+        .mount(&server)
+        .await;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("test").join("links"))
+            .arg("--check-url")
+            .arg(server.uri()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: `tqdm==1000.0.0` is yanked at: http://[LOCALHOST]/
+    ");
+
+    // The index doesn't provide `tqdm` at all.
+    let server = MockServer::start().await;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("test").join("links"))
+            .arg("--check-url")
+            .arg(server.uri()), @"
+    success: false
+    exit_code: 1
     ----- stdout -----
-    # This file was autogenerated by uv via the following command:
-    #    ./custom-uv-compile.sh
-    black==23.10.1
-        # via -r requirements.in
-    click==8.1.7
-        # via black
-    mypy-extensions==1.0.0
-        # via black
-    packaging==24.0
-        # via black
-    pathspec==0.12.1
-        # via black
-    platformdirs==4.2.0
-        # via black
 
     ----- stderr -----
-    Resolved 6 packages in [TIME]
-    "
-    );
+    Resolved 1 package in [TIME]
+    error: `tqdm==1000.0.0` is not available at: http://[LOCALHOST]/
+    ");
+
+    // The index provides the pinned version.
+    Mock::given(method("GET"))
+        .and(path("/tqdm/"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(
+            r#"<a href="https://example.com/tqdm-1000.0.0-py3-none-any.whl">tqdm-1000.0.0-py3-none-any.whl</a>"#,
+            "text/html",
+        ))
+        .mount(&server)
+        .await;
 
-    // with env var
     uv_snapshot!(context.filters(), context.pip_compile()
             .arg("requirements.in")
-            .env(EnvVars::UV_CUSTOM_COMPILE_COMMAND, "./custom-uv-compile.sh"), @"
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("test").join("links"))
+            .arg("--check-url")
+            .arg(server.uri()), @"
     success: true
     exit_code: 0
     ----- stdout -----
     # This file was autogenerated by uv via the following command:
-    #    ./custom-uv-compile.sh
-    black==23.10.1
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-index
+    tqdm==1000.0.0
         # via -r requirements.in
-    click==8.1.7
-        # via black
-    mypy-extensions==1.0.0
-        # via black
-    packaging==24.0
-        # via black
-    pathspec==0.12.1
-        # via black
-    platformdirs==4.2.0
-        # via black
 
     ----- stderr -----
-    Resolved 6 packages in [TIME]
-    "
-    );
+    Resolved 1 package in [TIME]
+    ");
 
     Ok(())
 }
@@ -12967,6 +13424,33 @@ fn compile_index_url_unsafe_lowest() -> Result<()> {
     Ok(())
 }
 
+/// Emit only the direct dependencies via `--emit-direct-only`, omitting transitives.
+#[test]
+fn emit_direct_only() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--emit-direct-only"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --emit-direct-only
+    anyio==3.7.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    warning: The `--emit-direct-only` output omits transitive dependencies and is not a complete locked environment; installing it may select different versions of transitive dependencies, or fail if they have incompatible requirements
+    "
+    );
+
+    Ok(())
+}
+
 /// Ensure that the username and the password are omitted when
 /// index annotations are displayed via `--emit-index-annotation`.
 #[test]
@@ -13125,6 +13609,241 @@ fn emit_index_annotation_line() -> Result<()> {
     Ok(())
 }
 
+/// Ensure that `--emit-package-urls` prints the download URL for each package.
+#[test]
+fn emit_package_urls() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("tqdm")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test").join("links"))
+        .arg("--emit-package-urls"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-index --emit-package-urls
+    tqdm==1000.0.0
+        # via -r requirements.in
+        # url: file://[WORKSPACE]/test/links/tqdm-1000.0.0-py3-none-any.whl
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+/// Ensure that `--group-by-extra` groups the output by the extras that request each package.
+#[test]
+fn group_by_extra() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        dependencies = ["ok"]
+
+        [project.optional-dependencies]
+        security = ["validation"]
+        cli = ["tqdm==4.66.1", "validation"]
+        all = ["project[cli]"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("pyproject.toml")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test").join("links"))
+        .arg("--python-platform")
+        .arg("linux")
+        .arg("--extra")
+        .arg("all")
+        .arg("--extra")
+        .arg("security")
+        .arg("--group-by-extra"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] pyproject.toml --no-index --python-platform linux --extra all --extra security --group-by-extra
+    ok==2.0.0
+        # via project (pyproject.toml)
+
+    # [extra: all]
+    tqdm==4.66.1
+        # via project (pyproject.toml)
+
+    # [extra: all, security]
+    validation==1.0.0
+        # via project (pyproject.toml)
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+/// Ensure that `--emit-license` prints the SPDX license expression for each package, and
+/// `UNKNOWN` for packages without one.
+#[test]
+fn emit_license() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("maturin==1.4.0\ntqdm==1000.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test").join("links"))
+        .arg("--emit-license"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-index --emit-license
+    maturin==1.4.0
+        # via -r requirements.in
+        # license: MIT OR Apache-2.0
+    tqdm==1000.0.0
+        # via -r requirements.in
+        # license: UNKNOWN
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+/// Ensure that `--emit-extras` annotates packages with the extras through which they were
+/// resolved.
+#[test]
+fn emit_extras() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("flask[dotenv]")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+            .arg("requirements.in")
+            .arg("--emit-extras"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --emit-extras
+    blinker==1.7.0
+        # via flask
+    click==8.1.7
+        # via flask
+    flask==3.0.2
+        # via -r requirements.in
+        # extras: dotenv
+    itsdangerous==2.1.2
+        # via flask
+    jinja2==3.1.3
+        # via flask
+    markupsafe==2.1.5
+        # via
+        #   jinja2
+        #   werkzeug
+    python-dotenv==1.0.1
+        # via flask
+    werkzeug==3.0.1
+        # via flask
+
+    ----- stderr -----
+    Resolved 8 packages in [TIME]
+    "
+    );
+
+    Ok(())
+}
+
+/// Ensure that `--fail-on-license` rejects packages that are only available under a rejected
+/// license.
+#[test]
+fn fail_on_license() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("maturin==1.4.0\ntqdm==1000.0.0")?;
+
+    // `maturin` is also available under `MIT`, so it's accepted. The license of `tqdm` is
+    // unknown, so it's accepted too.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test").join("links"))
+        .arg("--fail-on-license")
+        .arg("Apache-2.0"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --no-index --fail-on-license Apache-2.0
+    maturin==1.4.0
+        # via -r requirements.in
+    tqdm==1000.0.0
+        # via -r requirements.in
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "
+    );
+
+    // Rejecting both licenses rejects `maturin`.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test").join("links"))
+        .arg("--fail-on-license")
+        .arg("MIT OR Apache-2.0"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: `maturin==1.4.0` is licensed under a rejected license: `MIT OR Apache-2.0`
+    "
+    );
+
+    // The expression must be a valid SPDX license expression.
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--fail-on-license")
+        .arg("GPL"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'GPL' for '--fail-on-license <EXPRESSION>': GPL
+    ^^^ unknown term
+
+    For more information, try '--help'.
+    "
+    );
+
+    Ok(())
+}
+
 /// `--emit-index-annotation` where packages are pulled from two distinct indexes.
 #[test]
 fn emit_index_annotation_multiple_indexes() -> Result<()> {
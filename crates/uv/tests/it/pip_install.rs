@@ -1,5 +1,6 @@
 use std::io;
 use std::io::Cursor;
+use std::io::Write;
 use std::path::PathBuf;
 use std::process::Command;
 
@@ -1671,6 +1672,56 @@ fn install_editable_and_registry() {
     );
 }
 
+/// `--with-editable` installs the requirements, with the editable package overriding any other
+/// requirement for the same package.
+#[test]
+fn install_with_editable() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("black==24.3.0\niniconfig")?;
+
+    // Without `--with-editable`, the pinned version conflicts with the editable package.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("-e")
+        .arg(context.workspace_root.join("test/packages/black_editable")), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because only black<=0.1.0 is available and you require black==24.3.0, we can conclude that your requirements are unsatisfiable.
+    "
+    );
+
+    // `--with-editable` may be provided multiple times.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt")
+        .arg("--with-editable")
+        .arg(context.workspace_root.join("test/packages/black_editable"))
+        .arg("--with-editable")
+        .arg(context.workspace_root.join("test/packages/flit_editable")), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + black==0.1.0 (from file://[WORKSPACE]/test/packages/black_editable)
+     + flit-editable==0.1.0 (from file://[WORKSPACE]/test/packages/flit_editable)
+     + iniconfig==2.0.0
+    "
+    );
+
+    Ok(())
+}
+
 #[test]
 fn install_editable_no_binary() {
     let context = uv_test::test_context!("3.12");
@@ -3765,6 +3816,69 @@ fn install_constraints_txt() -> Result<()> {
     Ok(())
 }
 
+/// With `--constraint-strict`, installed packages outside the resolution must satisfy the
+/// constraints too.
+#[test]
+fn install_constraint_strict() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.pip_install()
+            .arg("idna==3.6"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + idna==3.6
+    "
+    );
+
+    let constraints_txt = context.temp_dir.child("constraints.txt");
+    constraints_txt.write_str("idna<3.4")?;
+
+    // `idna` isn't part of the resolution, so the violation is an error before anything is
+    // installed.
+    uv_snapshot!(context.pip_install()
+            .arg("iniconfig")
+            .arg("--constraint")
+            .arg("constraints.txt")
+            .arg("--constraint-strict"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: The environment would violate the constraints (with `--constraint-strict`):
+      `idna==3.6` does not satisfy the constraint `idna<3.4`
+    "
+    );
+
+    context.assert_not_installed("iniconfig");
+
+    // Without `--constraint-strict`, the installed `idna` is left as-is.
+    uv_snapshot!(context.pip_install()
+            .arg("iniconfig")
+            .arg("--constraint")
+            .arg("constraints.txt"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    Ok(())
+}
+
 /// Install a package from a `requirements.txt` file, with a `constraints.txt` file.
 #[test]
 #[expect(clippy::disallowed_types)]
@@ -3839,6 +3953,186 @@ fn install_constraints_from_pyproject() -> Result<()> {
     Ok(())
 }
 
+/// Check that `UV_CONSTRAINT_<NAME>` variables are respected with `--constraints-from-env`.
+#[test]
+fn install_constraints_from_env() {
+    let context = uv_test::test_context!("3.12");
+
+    // Without `--constraints-from-env`, the variables are ignored.
+    uv_snapshot!(context.pip_install()
+            .arg("ok")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("test").join("links"))
+            .arg("--dry-run")
+            .env("UV_CONSTRAINT_OK", "1.0.0"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + ok==2.0.0
+    "
+    );
+
+    // A bare version pins the package.
+    uv_snapshot!(context.pip_install()
+            .arg("ok")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("test").join("links"))
+            .arg("--constraints-from-env")
+            .env("UV_CONSTRAINT_OK", "1.0.0"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + ok==1.0.0
+    "
+    );
+
+    // Any other value is parsed as a version specifier.
+    uv_snapshot!(context.pip_install()
+            .arg("ok")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("test").join("links"))
+            .arg("--constraints-from-env")
+            .env("UV_CONSTRAINT_OK", "foo"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `UV_CONSTRAINT_OK` is not a valid version or version specifier
+      Caused by: Failed to parse version: Unexpected end of version specifier, expected operator:
+    foo
+    ^^^
+    "
+    );
+}
+
+/// Install a package with constraints from a TOML file.
+#[test]
+fn install_constraints_toml() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let constraints_toml = context.temp_dir.child("constraints.toml");
+    constraints_toml.write_str(indoc! {r#"
+        [constraints]
+        ok = "1.0.0"
+    "#})?;
+
+    // Files with a `.toml` extension are read as TOML.
+    uv_snapshot!(context.pip_install()
+            .arg("ok")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("test").join("links"))
+            .arg("--dry-run")
+            .arg("--constraint")
+            .arg("constraints.toml"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + ok==1.0.0
+    "
+    );
+
+    // Other files are read as TOML with `--constraint-format toml`.
+    let constraints_cfg = context.temp_dir.child("constraints.cfg");
+    constraints_cfg.write_str(indoc! {r#"
+        [constraints]
+        ok = "<2"
+    "#})?;
+
+    uv_snapshot!(context.pip_install()
+            .arg("ok")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("test").join("links"))
+            .arg("--dry-run")
+            .arg("--constraint")
+            .arg("constraints.cfg")
+            .arg("--constraint-format")
+            .arg("toml"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Would download 1 package
+    Would install 1 package
+     + ok==1.0.0
+    "
+    );
+
+    // The `[constraints]` table is required.
+    constraints_toml.write_str(indoc! {r#"
+        ok = "1.0.0"
+    "#})?;
+
+    uv_snapshot!(context.pip_install()
+            .arg("ok")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("test").join("links"))
+            .arg("--dry-run")
+            .arg("--constraint")
+            .arg("constraints.toml"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `constraints.toml`
+      Caused by: Missing `[constraints]` table
+    "
+    );
+
+    // Each value must be a version or a version specifier.
+    constraints_toml.write_str(indoc! {r#"
+        [constraints]
+        ok = "foo"
+    "#})?;
+
+    uv_snapshot!(context.pip_install()
+            .arg("ok")
+            .arg("--no-index")
+            .arg("--find-links")
+            .arg(context.workspace_root.join("test").join("links"))
+            .arg("--dry-run")
+            .arg("--constraint")
+            .arg("constraints.toml"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `constraints.toml`
+      Caused by: `foo` is not a valid version or version specifier for `ok`
+      Caused by: Failed to parse version: Unexpected end of version specifier, expected operator:
+    foo
+    ^^^
+    "
+    );
+
+    Ok(())
+}
+
 /// Install a package from a `requirements.txt` file, with an inline constraint.
 #[test]
 fn install_constraints_inline() -> Result<()> {
@@ -5554,18 +5848,68 @@ fn respect_no_build_isolation_env_var() -> Result<()> {
     Ok(())
 }
 
-/// This tests that uv can read UTF-16LE encoded requirements.txt files.
-///
-/// Ref: <https://github.com/astral-sh/uv/issues/2276>
+/// Pre-install packages into every isolated build environment with `--build-isolation-packages`.
 #[test]
-fn install_utf16le_requirements() -> Result<()> {
-    let context = uv_test::test_context!("3.12").with_exclude_newer("2025-01-01T00:00:00Z");
-    let requirements_txt = context.temp_dir.child("requirements.txt");
-    requirements_txt.write_binary(&utf8_to_utf16_with_bom_le("tomli<=2.0.1"))?;
+fn build_isolation_packages() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
 
-    uv_snapshot!(context.pip_install()
-        .arg("-r")
-        .arg("requirements.txt"), @"
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["hatchling"]
+        backend-path = ["."]
+        build-backend = "build_backend"
+    "#})?;
+
+    // Create a build backend that requires `anyio`, without declaring it.
+    context
+        .temp_dir
+        .child("build_backend.py")
+        .write_str(indoc! {r#"
+        import sys
+        from hatchling.build import *
+
+        try:
+            import anyio
+        except ModuleNotFoundError:
+            print("Missing `anyio` module", file=sys.stderr)
+            sys.exit(1)
+    "#})?;
+    context
+        .temp_dir
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .touch()?;
+
+    // We expect the build to fail, because `anyio` is not in the build environment.
+    uv_snapshot!(context.filters(), context.pip_install().arg("."), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `project @ file://[TEMP_DIR]/`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `build_backend.build_wheel` failed (exit status: 1)
+
+          [stderr]
+          Missing `anyio` module
+
+          hint: This usually indicates a problem with the package or the build environment.
+    ");
+
+    // We expect the build to succeed, since `anyio` is pre-installed into the build environment.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(".")
+        .arg("--build-isolation-packages")
+        .arg("anyio"), @"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -5574,14 +5918,151 @@ fn install_utf16le_requirements() -> Result<()> {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + tomli==2.0.1
-    "
-    );
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+    ");
+
+    // `anyio` is only installed into the build environment, not the target environment.
+    context.assert_not_installed("anyio");
+
     Ok(())
 }
 
-/// This tests that uv can read UTF-16BE encoded requirements.txt files.
-///
+/// A wheel built with `--build-isolation-packages` shouldn't be reused for a build without them,
+/// and vice versa.
+#[test]
+fn build_isolation_packages_cache() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+
+        [build-system]
+        requires = ["hatchling"]
+        backend-path = ["."]
+        build-backend = "build_backend"
+    "#};
+
+    // Create a build backend that requires `anyio`, without declaring it.
+    let build_backend = indoc! {r#"
+        import sys
+        from hatchling.build import *
+
+        try:
+            import anyio
+        except ModuleNotFoundError:
+            print("Missing `anyio` module", file=sys.stderr)
+            sys.exit(1)
+    "#};
+
+    // Unlike a source tree, a source distribution is built once and cached.
+    let source_dist = context.temp_dir.child("project-0.1.0.tar.gz");
+    // Flush the file after we're done.
+    {
+        let file = File::create(source_dist.path())?;
+        let enc = GzEncoder::new(file, flate2::Compression::default());
+        let mut tar = tar::Builder::new(enc);
+
+        for (path, contents) in [
+            ("project-0.1.0/pyproject.toml", pyproject_toml),
+            ("project-0.1.0/build_backend.py", build_backend),
+            ("project-0.1.0/src/project/__init__.py", ""),
+        ] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(contents.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            tar.append_data(&mut header, path, Cursor::new(contents))?;
+        }
+        tar.finish()?;
+    }
+
+    // Build the project with `anyio` pre-installed into the build environment.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(source_dist.path())
+        .arg("--build-isolation-packages")
+        .arg("anyio"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/project-0.1.0.tar.gz)
+    ");
+
+    // Without `--build-isolation-packages`, the cached wheel isn't reused, so the project is
+    // built again, which fails, since `anyio` is not in the build environment.
+    context.reset_venv();
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(source_dist.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `project @ file://[TEMP_DIR]/project-0.1.0.tar.gz`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `build_backend.build_wheel` failed (exit status: 1)
+
+          [stderr]
+          Missing `anyio` module
+
+          hint: This usually indicates a problem with the package or the build environment.
+    ");
+
+    // With `--build-isolation-packages`, the cached wheel is reused.
+    context.reset_venv();
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(source_dist.path())
+        .arg("--build-isolation-packages")
+        .arg("anyio"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + project==0.1.0 (from file://[TEMP_DIR]/project-0.1.0.tar.gz)
+    ");
+
+    Ok(())
+}
+
+/// This tests that uv can read UTF-16LE encoded requirements.txt files.
+///
+/// Ref: <https://github.com/astral-sh/uv/issues/2276>
+#[test]
+fn install_utf16le_requirements() -> Result<()> {
+    let context = uv_test::test_context!("3.12").with_exclude_newer("2025-01-01T00:00:00Z");
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_binary(&utf8_to_utf16_with_bom_le("tomli<=2.0.1"))?;
+
+    uv_snapshot!(context.pip_install()
+        .arg("-r")
+        .arg("requirements.txt"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tomli==2.0.1
+    "
+    );
+    Ok(())
+}
+
+/// This tests that uv can read UTF-16BE encoded requirements.txt files.
+///
 /// Ref: <https://github.com/astral-sh/uv/issues/2276>
 #[test]
 fn install_utf16be_requirements() -> Result<()> {
@@ -9802,11 +10283,563 @@ fn resolve_derivation_chain() -> Result<()> {
 fn respect_no_installer_metadata_env_var() {
     let context = uv_test::test_context!("3.12");
 
-    // Install urllib3.
-    uv_snapshot!(context.pip_install()
-        .arg("urllib3==2.2.1")
-        .arg("--strict")
-        .env(EnvVars::UV_NO_INSTALLER_METADATA, "1"), @"
+    // Install urllib3.
+    uv_snapshot!(context.pip_install()
+        .arg("urllib3==2.2.1")
+        .arg("--strict")
+        .env(EnvVars::UV_NO_INSTALLER_METADATA, "1"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + urllib3==2.2.1
+    "
+    );
+
+    context.assert_command("import urllib3").success();
+
+    // Assert INSTALLER file was _not_ created.
+    let installer_file = context
+        .site_packages()
+        .join("urllib3-2.2.3.dist-info")
+        .join("INSTALLER");
+    assert!(!installer_file.exists());
+}
+
+/// Ensure that `--no-attrs`, an alias of `--no-installer-metadata`, omits the optional
+/// `.dist-info` files.
+#[test]
+fn no_attrs() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("tqdm")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test").join("links"))
+        .arg("--no-attrs"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0
+    "
+    );
+
+    // Assert that only the files shipped in the wheel were written.
+    let dist_info = context.site_packages().join("tqdm-1000.0.0.dist-info");
+    assert!(dist_info.join("METADATA").exists());
+    assert!(dist_info.join("RECORD").exists());
+    assert!(dist_info.join("WHEEL").exists());
+    assert!(!dist_info.join("INSTALLER").exists());
+    assert!(!dist_info.join("REQUESTED").exists());
+    assert!(!dist_info.join("direct_url.json").exists());
+}
+
+/// Persist the credentials for an authenticated index with `--save-credentials`.
+#[tokio::test]
+async fn save_credentials() {
+    let context = uv_test::test_context!("3.12");
+    let proxy = crate::pypi_proxy::start().await;
+
+    // `tqdm` isn't served by the index, so it's installed from the local `--find-links` instead.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("tqdm")
+        .arg("--default-index")
+        .arg(format!("internal={}", proxy.authenticated_url("public", "heron", "/basic-auth/simple")))
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test").join("links"))
+        .arg("--save-credentials")
+        .arg("env"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    UV_INDEX_INTERNAL_USERNAME=public
+    UV_INDEX_INTERNAL_PASSWORD=heron
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("tqdm")
+        .arg("--reinstall")
+        .arg("--index-url")
+        .arg(proxy.authenticated_url("public", "heron", "/basic-auth/simple"))
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test").join("links"))
+        .arg("--save-credentials")
+        .arg("file"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ tqdm==1000.0.0
+    Stored credentials for public@http://[LOCALHOST]/basic-auth
+    "
+    );
+
+    // The stored credentials are recalled without providing them again.
+    uv_snapshot!(context.filters(), context.auth_token()
+        .arg(proxy.url("/basic-auth/simple"))
+        .arg("--username")
+        .arg("public"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    heron
+
+    ----- stderr -----
+    "
+    );
+}
+
+/// Warn when `--save-credentials` is used without any index credentials.
+#[test]
+fn save_credentials_missing() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("tqdm")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test").join("links"))
+        .arg("--save-credentials")
+        .arg("file"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0
+    warning: No index credentials were provided; nothing to save for `--save-credentials`
+    "
+    );
+}
+
+/// Downloads from a host allowed via `--trusted-host` emit a warning.
+#[tokio::test]
+async fn trusted_host_download_warning() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let wheel = fs_err::read(
+        context
+            .workspace_root
+            .join("test")
+            .join("links")
+            .join("tqdm-1000.0.0-py3-none-any.whl"),
+    )?;
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/tqdm-1000.0.0-py3-none-any.whl"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(wheel))
+        .mount(&server)
+        .await;
+    let requirement = format!("tqdm @ {}/tqdm-1000.0.0-py3-none-any.whl", server.uri());
+    let host = server.address().ip().to_string();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(&requirement)
+        .arg("--trusted-host")
+        .arg(&host), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: Downloading `tqdm @ http://[LOCALHOST]/tqdm-1000.0.0-py3-none-any.whl` from `127.0.0.1` over an unencrypted connection (allowed by `--allow-insecure-host`)
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0 (from http://[LOCALHOST]/tqdm-1000.0.0-py3-none-any.whl)
+    "
+    );
+
+    // Trusted hosts can also be configured via `allow-insecure-host`.
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str(&formatdoc! {r#"
+        allow-insecure-host = ["{host}"]
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(&requirement)
+        .arg("--reinstall")
+        .arg("--no-cache"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    warning: Downloading `tqdm @ http://[LOCALHOST]/tqdm-1000.0.0-py3-none-any.whl` from `127.0.0.1` over an unencrypted connection (allowed by `--allow-insecure-host`)
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ tqdm==1000.0.0 (from http://[LOCALHOST]/tqdm-1000.0.0-py3-none-any.whl)
+    "
+    );
+
+    Ok(())
+}
+
+/// `--platform-override` substitutes the given platform tag when selecting wheels.
+#[test]
+fn platform_override() {
+    let context = uv_test::test_context!("3.12");
+
+    // The only `tqdm==4.66.1` distribution is a `manylinux2010` wheel, which isn't selected when
+    // pretending to be a `manylinux2014` system.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("tqdm==4.66.1")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test").join("links"))
+        .arg("--platform-override")
+        .arg("manylinux2014_x86_64"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+      × No solution found when resolving dependencies:
+      ╰─▶ Because tqdm==4.66.1 has no wheels with a matching platform tag (e.g., `manylinux2014_x86_64`) and you require tqdm==4.66.1, we can conclude that your requirements are unsatisfiable.
+
+          hint: Wheels are available for `tqdm` (v4.66.1) on the following platforms: `manylinux_2_12_x86_64`, `manylinux2010_x86_64`, `musllinux_1_1_x86_64`
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("tqdm==4.66.1")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test").join("links"))
+        .arg("--platform-override")
+        .arg("manylinux2010_x86_64"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==4.66.1
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("tqdm")
+        .arg("--platform-override")
+        .arg("manylinux_x86_64"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'manylinux_x86_64' for '--platform-override <PLATFORM_OVERRIDE>': Invalid format for manylinux platform tag: manylinux_x86_64
+
+    For more information, try '--help'.
+    "
+    );
+}
+
+/// `--wheel-tag` selects the wheel built for the given tag over other compatible wheels.
+#[test]
+fn wheel_tag() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    // Write two wheels for the same version, which differ only in their tags.
+    let links = context.temp_dir.child("links");
+    links.create_dir_all()?;
+    for tag in ["py3-none-any", "py312-none-any"] {
+        let mut writer = ZipWriter::new(File::create(links.join(format!("foo-1.0.0-{tag}.whl")))?);
+        let options = SimpleFileOptions::default();
+        writer.start_file("foo/__init__.py", options)?;
+        writeln!(writer, "TAG = {tag:?}")?;
+        writer.start_file("foo-1.0.0.dist-info/METADATA", options)?;
+        writer.write_all(indoc! {b"
+            Metadata-Version: 2.1
+            Name: foo
+            Version: 1.0.0
+        "})?;
+        writer.start_file("foo-1.0.0.dist-info/WHEEL", options)?;
+        writer.write_all(
+            formatdoc! {"
+                Wheel-Version: 1.0
+                Root-Is-Purelib: true
+                Tag: {tag}
+            "}
+            .as_bytes(),
+        )?;
+        writer.start_file("foo-1.0.0.dist-info/RECORD", options)?;
+        writer.write_all(indoc! {b"
+            foo/__init__.py,,
+            foo-1.0.0.dist-info/METADATA,,
+            foo-1.0.0.dist-info/WHEEL,,
+            foo-1.0.0.dist-info/RECORD,,
+        "})?;
+        writer.finish()?;
+    }
+
+    // By default, the more specific `py312` wheel is selected.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("foo")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + foo==1.0.0
+    "
+    );
+
+    assert_eq!(
+        fs_err::read_to_string(context.site_packages().join("foo").join("__init__.py"))?,
+        "TAG = \"py312-none-any\"\n"
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("foo")
+        .arg("--reinstall")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(links.path())
+        .arg("--wheel-tag")
+        .arg("foo:py3-none-any"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ foo==1.0.0
+    "
+    );
+
+    assert_eq!(
+        fs_err::read_to_string(context.site_packages().join("foo").join("__init__.py"))?,
+        "TAG = \"py3-none-any\"\n"
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("foo")
+        .arg("--wheel-tag")
+        .arg("foo:py3-none"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: invalid value 'foo:py3-none' for '--wheel-tag <WHEEL_TAG>': Invalid `wheel-tag` tag `py3-none`: expected format `PYTHON-ABI-PLATFORM`
+
+    For more information, try '--help'.
+    "
+    );
+
+    Ok(())
+}
+
+/// `--record` appends the files installed by each invocation to a session `RECORD` file.
+#[test]
+fn install_record() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(
+            r"uv_cache\.json,sha256=[\w-]+,\d+",
+            "uv_cache.json,sha256=[SHA256],[SIZE]",
+        )])
+        .collect::<Vec<_>>();
+
+    let record = context.temp_dir.child("record.csv");
+
+    uv_snapshot!(filters, context.pip_install()
+        .arg("tqdm==1000.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test").join("links"))
+        .arg("--record")
+        .arg(record.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0
+    "
+    );
+
+    let contents = apply_filters(fs_err::read_to_string(&record)?, &filters);
+    assert_snapshot!(contents, @"
+    tqdm-1000.0.0.dist-info/INSTALLER,sha256=5hhM4Q4mYTT9z6QB6PGpUAW81PGNFrYrdXMj4oM_6ak,2,tqdm,1000.0.0
+    tqdm-1000.0.0.dist-info/METADATA,sha256=WjHrSNIxwdFRHXJ6i3cDEGtb3JTzeTFiCYfkqiSQpuY,383,tqdm,1000.0.0
+    tqdm-1000.0.0.dist-info/RECORD,,,tqdm,1000.0.0
+    tqdm-1000.0.0.dist-info/REQUESTED,sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU,0,tqdm,1000.0.0
+    tqdm-1000.0.0.dist-info/WHEEL,sha256=FMvqSimYX_P7y0a7UY-_Mc83r5zkBZsCYPm7Lr0Bsq4,88,tqdm,1000.0.0
+    tqdm-1000.0.0.dist-info/uv_cache.json,sha256=[SHA256],[SIZE],tqdm,1000.0.0
+    tqdm/__init__.py,sha256=47DEQpj8HBSa-_TImW-5JCeuQeRkm5NMpJWZG3hSuFU,0,tqdm,1000.0.0
+    "
+    );
+
+    // Installing again is a no-op, and leaves the record untouched.
+    uv_snapshot!(filters, context.pip_install()
+        .arg("tqdm==1000.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test").join("links"))
+        .arg("--record")
+        .arg(record.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 1 package in [TIME]
+    "
+    );
+
+    // Reinstalling appends the same entries.
+    uv_snapshot!(filters, context.pip_install()
+        .arg("tqdm==1000.0.0")
+        .arg("--reinstall")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test").join("links"))
+        .arg("--record")
+        .arg(record.path()), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ tqdm==1000.0.0
+    "
+    );
+
+    assert_eq!(
+        apply_filters(fs_err::read_to_string(&record)?, &filters),
+        contents.repeat(2)
+    );
+
+    Ok(())
+}
+
+/// `--clean-env` removes interfering Python variables from the build backend's environment.
+#[test]
+fn install_clean_env() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+
+        [build-system]
+        requires = []
+        backend-path = ["."]
+        build-backend = "build_backend"
+    "#})?;
+
+    // A build backend that fails if `PYTHONPATH` is set.
+    context
+        .temp_dir
+        .child("build_backend.py")
+        .write_str(indoc! {r#"
+        import os
+        import sys
+        import zipfile
+
+        def build_wheel(wheel_directory, config_settings=None, metadata_directory=None):
+            if "PYTHONPATH" in os.environ:
+                print("`PYTHONPATH` is set", file=sys.stderr)
+                sys.exit(1)
+
+            filename = "project-0.1.0-py3-none-any.whl"
+            with zipfile.ZipFile(os.path.join(wheel_directory, filename), "w") as wheel:
+                wheel.writestr(
+                    "project-0.1.0.dist-info/METADATA",
+                    "Metadata-Version: 2.1\nName: project\nVersion: 0.1.0\n",
+                )
+                wheel.writestr(
+                    "project-0.1.0.dist-info/WHEEL",
+                    "Wheel-Version: 1.0\nRoot-Is-Purelib: true\nTag: py3-none-any\n",
+                )
+                wheel.writestr(
+                    "project-0.1.0.dist-info/RECORD",
+                    "project-0.1.0.dist-info/METADATA,,\n"
+                    "project-0.1.0.dist-info/WHEEL,,\n"
+                    "project-0.1.0.dist-info/RECORD,,\n",
+                )
+            return filename
+    "#})?;
+
+    let python_path = context.temp_dir.child("python-path");
+    python_path.create_dir_all()?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(".")
+        .env(EnvVars::PYTHONPATH, python_path.path()), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+      × Failed to build `project @ file://[TEMP_DIR]/`
+      ├─▶ The build backend returned an error
+      ╰─▶ Call to `build_backend.build_wheel` failed (exit status: 1)
+
+          [stderr]
+          `PYTHONPATH` is set
+
+          hint: This usually indicates a problem with the package or the build environment.
+    "
+    );
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg(".")
+        .arg("--clean-env")
+        .env(EnvVars::PYTHONPATH, python_path.path()), @"
     success: true
     exit_code: 0
     ----- stdout -----
@@ -9815,18 +10848,11 @@ fn respect_no_installer_metadata_env_var() {
     Resolved 1 package in [TIME]
     Prepared 1 package in [TIME]
     Installed 1 package in [TIME]
-     + urllib3==2.2.1
+     + project==0.1.0 (from file://[TEMP_DIR]/)
     "
     );
 
-    context.assert_command("import urllib3").success();
-
-    // Assert INSTALLER file was _not_ created.
-    let installer_file = context
-        .site_packages()
-        .join("urllib3-2.2.3.dist-info")
-        .join("INSTALLER");
-    assert!(!installer_file.exists());
+    Ok(())
 }
 
 /// Check that we error if a source dist lies about its built wheel version.
@@ -12885,6 +13911,173 @@ fn offline_refresh_conflict() {
     );
 }
 
+/// Test that `--no-cache-metadata` re-fetches metadata, but reuses cached wheels.
+#[test]
+fn no_cache_metadata() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    // Re-install into a fresh environment with `--no-cache-metadata`. The wheel should be reused
+    // from the cache, rather than downloaded again.
+    context.reset_venv();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--no-cache-metadata"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+}
+
+#[test]
+fn no_wheel_cache() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--no-wheel-cache"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+
+    // The wheel shouldn't be persisted to the cache, but the index page should.
+    assert!(!context.cache_dir.child("wheels-v6").exists());
+    assert!(!context.cache_dir.child("archive-v0").exists());
+    assert!(context.cache_dir.child("simple-v21").exists());
+
+    // Re-install into a fresh environment. The wheel should be downloaded again.
+    context.reset_venv();
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "
+    );
+}
+
+/// Reject symlink semantics with `--no-wheel-cache`, since the linked wheels are removed once uv
+/// exits.
+#[test]
+fn no_wheel_cache_symlink() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--link-mode")
+        .arg("symlink")
+        .arg("--no-wheel-cache"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    error: Symlink-based installation is not supported with `--no-wheel-cache`. The created environment will be rendered unusable by the removal of the downloaded wheels.
+    "
+    );
+
+    // Nothing should be installed.
+    context.assert_command("import iniconfig").failure();
+}
+
+/// Test that `--show-plan` prints the installation plan, and proceeds without prompting when
+/// stderr isn't a terminal.
+#[test]
+fn show_plan() {
+    let context = uv_test::test_context!("3.12");
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==1.1.1")
+        .arg("--show-plan"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installation plan:
+     + iniconfig==1.1.1 (4.9KiB)
+    Total download size: 4.9KiB
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==1.1.1
+    "
+    );
+
+    // Upgrades are shown as a single entry.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--show-plan")
+        .arg("--yes"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Installation plan:
+     ~ iniconfig 1.1.1 -> 2.0.0 (5.8KiB)
+    Total download size: 5.8KiB
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     - iniconfig==1.1.1
+     + iniconfig==2.0.0
+    "
+    );
+
+    // Nothing is printed if the environment is already satisfied.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig==2.0.0")
+        .arg("--show-plan"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Checked 1 package in [TIME]
+    "
+    );
+}
+
 /// Test that shebang arguments are stripped when installing scripts
 #[test]
 #[cfg(unix)]
@@ -15134,3 +16327,181 @@ fn handle_record_mismatches() -> Result<()> {
 
     Ok(())
 }
+
+/// `--hooks-pre` and `--hooks-post` run scripts around the installation, passing the changes on
+/// stdin.
+#[test]
+#[cfg(unix)]
+fn install_hooks() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let context = uv_test::test_context!("3.12");
+
+    let log = context.temp_dir.child("hooks.log");
+    let write_script = |name: &str, contents: String| -> Result<PathBuf> {
+        let script = context.temp_dir.child(name);
+        script.write_str(&contents)?;
+        let mut permissions = fs_err::metadata(&script)?.permissions();
+        permissions.set_mode(0o755);
+        fs_err::set_permissions(&script, permissions)?;
+        Ok(script.to_path_buf())
+    };
+    let hook = |phase: &str| {
+        formatdoc! {r#"
+            #!/bin/sh
+            {{
+                echo "{phase}: $UV_HOOK_PACKAGES in $UV_HOOK_PREFIX"
+                cat
+                echo
+            }} >> "{log}"
+        "#, log = log.display()}
+    };
+    let pre = write_script("pre.sh", hook("pre"))?;
+    let post = write_script("post.sh", hook("post"))?;
+    let fail = write_script("fail.sh", "#!/bin/sh\nexit 3\n".to_string())?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("tqdm==1000.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test").join("links"))
+        .arg("--hooks-pre")
+        .arg(&pre)
+        .arg("--hooks-post")
+        .arg(&post), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + tqdm==1000.0.0
+    "
+    );
+
+    let contents = apply_filters(fs_err::read_to_string(&log)?, context.filters());
+    assert_snapshot!(contents, @r#"
+    pre: tqdm in [VENV]/
+    {"install":[{"name":"tqdm","version":"1000.0.0"}],"reinstall":[],"uninstall":[]}
+    post: tqdm in [VENV]/
+    {"install":[{"name":"tqdm","version":"1000.0.0"}],"reinstall":[],"uninstall":[]}
+    "#
+    );
+
+    // If the environment is already up-to-date, the hooks aren't run.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("tqdm==1000.0.0")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test").join("links"))
+        .arg("--hooks-pre")
+        .arg(&fail), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 1 package in [TIME]
+    "
+    );
+
+    // A failing `--hooks-pre` script aborts the installation.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("tqdm==1000.0.0")
+        .arg("--reinstall")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(context.workspace_root.join("test").join("links"))
+        .arg("--hooks-pre")
+        .arg(&fail), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: `--hooks-pre` script `fail.sh` failed with exit status: 3
+    "
+    );
+
+    Ok(())
+}
+
+/// `--post-install-check` imports each installed package, warning about those that fail.
+#[test]
+fn post_install_check() -> Result<()> {
+    let context = uv_test::test_context!("3.12");
+
+    let project = context.temp_dir.child("project");
+    project.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+
+        [build-system]
+        requires = ["uv_build>=0.7,<10000"]
+        build-backend = "uv_build"
+    "#})?;
+    project
+        .child("src")
+        .child("project")
+        .child("__init__.py")
+        .write_str("raise ImportError('libfoo.so: cannot open shared object file')\n")?;
+
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./project")
+        .arg("iniconfig==2.0.0")
+        .arg("--post-install-check"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/project)
+    warning: Package `project==0.1.0` was installed, but failed to import: ImportError: libfoo.so: cannot open shared object file
+    "
+    );
+
+    // With `--strict-post-install-check`, the failure is an error.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./project")
+        .arg("--reinstall-package")
+        .arg("project")
+        .arg("--post-install-check")
+        .arg("--strict-post-install-check"), @"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 1 package in [TIME]
+     ~ project==0.1.0 (from file://[TEMP_DIR]/project)
+    warning: Package `project==0.1.0` was installed, but failed to import: ImportError: libfoo.so: cannot open shared object file
+    "
+    );
+
+    // Packages that were already installed aren't checked.
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("./project")
+        .arg("--post-install-check")
+        .arg("--strict-post-install-check"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 1 package in [TIME]
+    "
+    );
+
+    Ok(())
+}
@@ -97,6 +97,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -106,6 +107,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -212,11 +216,13 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: true,
             config_setting: ConfigSettings(
                 {},
@@ -239,6 +245,13 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -310,6 +323,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -319,6 +333,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -425,11 +442,13 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: true,
             config_setting: ConfigSettings(
                 {},
@@ -452,6 +471,13 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -524,6 +550,7 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -533,6 +560,9 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -639,11 +669,13 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -666,6 +698,13 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -770,6 +809,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -779,6 +819,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -885,11 +928,13 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: true,
             config_setting: ConfigSettings(
                 {},
@@ -912,6 +957,13 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -985,6 +1037,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -994,6 +1047,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1062,11 +1118,13 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -1089,6 +1147,13 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -1172,6 +1237,7 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -1181,6 +1247,9 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1285,11 +1354,13 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: true,
             config_setting: ConfigSettings(
                 {},
@@ -1314,6 +1385,13 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -1410,6 +1488,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -1419,6 +1498,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1558,11 +1640,13 @@ fn resolve_index_url() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -1585,6 +1669,13 @@ fn resolve_index_url() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -1658,6 +1749,7 @@ fn resolve_index_url() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -1667,6 +1759,9 @@ fn resolve_index_url() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -1843,11 +1938,13 @@ fn resolve_index_url() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -1870,6 +1967,13 @@ fn resolve_index_url() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -1966,6 +2070,7 @@ fn resolve_find_links() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -1975,6 +2080,9 @@ fn resolve_find_links() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2079,11 +2187,13 @@ fn resolve_find_links() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -2106,6 +2216,13 @@ fn resolve_find_links() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -2201,6 +2318,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -2210,6 +2328,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2278,11 +2399,13 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -2305,6 +2428,13 @@ fn resolve_top_level() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -2393,6 +2523,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -2402,6 +2533,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2541,11 +2675,13 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -2568,6 +2704,13 @@ fn resolve_top_level() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -2639,6 +2782,7 @@ fn resolve_top_level() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -2648,6 +2792,9 @@ fn resolve_top_level() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2787,11 +2934,13 @@ fn resolve_top_level() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -2814,6 +2963,13 @@ fn resolve_top_level() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -2908,6 +3064,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -2917,6 +3074,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -2985,11 +3145,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -3012,6 +3174,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -3090,6 +3259,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -3099,6 +3269,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3167,11 +3340,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: true,
             config_setting: ConfigSettings(
                 {},
@@ -3194,6 +3369,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -3272,6 +3454,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -3281,6 +3464,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3349,11 +3535,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -3376,6 +3564,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -3456,6 +3651,7 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -3465,6 +3661,9 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3533,11 +3732,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -3560,6 +3761,13 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -3759,6 +3967,7 @@ fn resolve_tool() -> anyhow::Result<()> {
             reinstall: None,
         },
         force: false,
+        gui_detach: false,
         editable: false,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
@@ -3857,6 +4066,7 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -3866,6 +4076,9 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -3934,11 +4147,13 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -3961,6 +4176,13 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -4073,6 +4295,7 @@ fn resolve_both() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -4082,6 +4305,9 @@ fn resolve_both() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4188,11 +4414,13 @@ fn resolve_both() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: true,
             config_setting: ConfigSettings(
                 {},
@@ -4215,6 +4443,13 @@ fn resolve_both() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -4332,6 +4567,7 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -4341,6 +4577,9 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4447,11 +4686,13 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: true,
             config_setting: ConfigSettings(
                 {},
@@ -4474,6 +4715,13 @@ fn resolve_both_special_fields() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -4670,6 +4918,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -4679,6 +4928,9 @@ fn resolve_config_file() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -4783,11 +5035,13 @@ fn resolve_config_file() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: true,
             config_setting: ConfigSettings(
                 {},
@@ -4810,6 +5064,13 @@ fn resolve_config_file() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -4981,6 +5242,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -4990,6 +5252,9 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5058,11 +5323,13 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -5085,6 +5352,13 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -5166,6 +5440,7 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -5175,6 +5450,9 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5243,11 +5521,13 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -5270,6 +5550,13 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -5370,6 +5657,7 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -5379,6 +5667,9 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5447,11 +5738,13 @@ fn allow_insecure_host() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -5474,6 +5767,13 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -5566,6 +5866,7 @@ fn index_priority() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -5575,6 +5876,9 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5718,11 +6022,13 @@ fn index_priority() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -5745,6 +6051,13 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -5816,6 +6129,7 @@ fn index_priority() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -5825,6 +6139,9 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -5968,11 +6285,13 @@ fn index_priority() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -5995,6 +6314,13 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -6072,6 +6398,7 @@ fn index_priority() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -6081,6 +6408,9 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6224,11 +6554,13 @@ fn index_priority() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -6251,6 +6583,13 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -6323,6 +6662,7 @@ fn index_priority() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -6332,6 +6672,9 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6475,11 +6818,13 @@ fn index_priority() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -6502,6 +6847,13 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -6581,6 +6933,7 @@ fn index_priority() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -6590,6 +6943,9 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6733,11 +7089,13 @@ fn index_priority() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -6760,6 +7118,13 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -6832,6 +7197,7 @@ fn index_priority() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -6841,6 +7207,9 @@ fn index_priority() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -6984,11 +7353,13 @@ fn index_priority() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -7011,6 +7382,13 @@ fn index_priority() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -7095,11 +7473,29 @@ fn verify_hashes() -> anyhow::Result<()> {
             "requirements.in",
         ],
         editables: [],
+        with_editables: [],
         constraints: [],
+        constraint_format: None,
         overrides: [],
         excludes: [],
         build_constraints: [],
+        build_isolation_packages: [],
         dry_run: Disabled,
+        show_plan: Disabled,
+        clean_env: false,
+        record: None,
+        hooks: InstallHooks {
+            pre: None,
+            post: None,
+        },
+        post_install_check: Disabled,
+        save_credentials: None,
+        platform_override: None,
+        wheel_tags: WheelTags(
+            {},
+        ),
+        constraints_from_env: false,
+        constraint_strict: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7113,6 +7509,8 @@ fn verify_hashes() -> anyhow::Result<()> {
                 },
             ),
         ),
+        no_cache_metadata: false,
+        no_wheel_cache: false,
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -7173,11 +7571,13 @@ fn verify_hashes() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -7200,6 +7600,13 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -7270,11 +7677,29 @@ fn verify_hashes() -> anyhow::Result<()> {
             "requirements.in",
         ],
         editables: [],
+        with_editables: [],
         constraints: [],
+        constraint_format: None,
         overrides: [],
         excludes: [],
         build_constraints: [],
+        build_isolation_packages: [],
         dry_run: Disabled,
+        show_plan: Disabled,
+        clean_env: false,
+        record: None,
+        hooks: InstallHooks {
+            pre: None,
+            post: None,
+        },
+        post_install_check: Disabled,
+        save_credentials: None,
+        platform_override: None,
+        wheel_tags: WheelTags(
+            {},
+        ),
+        constraints_from_env: false,
+        constraint_strict: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7288,6 +7713,8 @@ fn verify_hashes() -> anyhow::Result<()> {
                 },
             ),
         ),
+        no_cache_metadata: false,
+        no_wheel_cache: false,
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -7348,11 +7775,13 @@ fn verify_hashes() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -7375,6 +7804,13 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -7443,11 +7879,29 @@ fn verify_hashes() -> anyhow::Result<()> {
             "requirements.in",
         ],
         editables: [],
+        with_editables: [],
         constraints: [],
+        constraint_format: None,
         overrides: [],
         excludes: [],
         build_constraints: [],
+        build_isolation_packages: [],
         dry_run: Disabled,
+        show_plan: Disabled,
+        clean_env: false,
+        record: None,
+        hooks: InstallHooks {
+            pre: None,
+            post: None,
+        },
+        post_install_check: Disabled,
+        save_credentials: None,
+        platform_override: None,
+        wheel_tags: WheelTags(
+            {},
+        ),
+        constraints_from_env: false,
+        constraint_strict: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7461,6 +7915,8 @@ fn verify_hashes() -> anyhow::Result<()> {
                 },
             ),
         ),
+        no_cache_metadata: false,
+        no_wheel_cache: false,
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -7521,11 +7977,13 @@ fn verify_hashes() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -7548,6 +8006,13 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -7618,11 +8083,29 @@ fn verify_hashes() -> anyhow::Result<()> {
             "requirements.in",
         ],
         editables: [],
+        with_editables: [],
         constraints: [],
+        constraint_format: None,
         overrides: [],
         excludes: [],
         build_constraints: [],
+        build_isolation_packages: [],
         dry_run: Disabled,
+        show_plan: Disabled,
+        clean_env: false,
+        record: None,
+        hooks: InstallHooks {
+            pre: None,
+            post: None,
+        },
+        post_install_check: Disabled,
+        save_credentials: None,
+        platform_override: None,
+        wheel_tags: WheelTags(
+            {},
+        ),
+        constraints_from_env: false,
+        constraint_strict: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7636,6 +8119,8 @@ fn verify_hashes() -> anyhow::Result<()> {
                 },
             ),
         ),
+        no_cache_metadata: false,
+        no_wheel_cache: false,
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -7696,11 +8181,13 @@ fn verify_hashes() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -7723,6 +8210,13 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -7791,11 +8285,29 @@ fn verify_hashes() -> anyhow::Result<()> {
             "requirements.in",
         ],
         editables: [],
+        with_editables: [],
         constraints: [],
+        constraint_format: None,
         overrides: [],
         excludes: [],
         build_constraints: [],
+        build_isolation_packages: [],
         dry_run: Disabled,
+        show_plan: Disabled,
+        clean_env: false,
+        record: None,
+        hooks: InstallHooks {
+            pre: None,
+            post: None,
+        },
+        post_install_check: Disabled,
+        save_credentials: None,
+        platform_override: None,
+        wheel_tags: WheelTags(
+            {},
+        ),
+        constraints_from_env: false,
+        constraint_strict: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7809,6 +8321,8 @@ fn verify_hashes() -> anyhow::Result<()> {
                 },
             ),
         ),
+        no_cache_metadata: false,
+        no_wheel_cache: false,
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -7869,11 +8383,13 @@ fn verify_hashes() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -7896,6 +8412,13 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -7965,11 +8488,29 @@ fn verify_hashes() -> anyhow::Result<()> {
             "requirements.in",
         ],
         editables: [],
+        with_editables: [],
         constraints: [],
+        constraint_format: None,
         overrides: [],
         excludes: [],
         build_constraints: [],
+        build_isolation_packages: [],
         dry_run: Disabled,
+        show_plan: Disabled,
+        clean_env: false,
+        record: None,
+        hooks: InstallHooks {
+            pre: None,
+            post: None,
+        },
+        post_install_check: Disabled,
+        save_credentials: None,
+        platform_override: None,
+        wheel_tags: WheelTags(
+            {},
+        ),
+        constraints_from_env: false,
+        constraint_strict: false,
         constraints_from_workspace: [],
         overrides_from_workspace: [],
         excludes_from_workspace: [],
@@ -7983,6 +8524,8 @@ fn verify_hashes() -> anyhow::Result<()> {
                 },
             ),
         ),
+        no_cache_metadata: false,
+        no_wheel_cache: false,
         settings: PipSettings {
             index_locations: IndexLocations {
                 indexes: [],
@@ -8043,11 +8586,13 @@ fn verify_hashes() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -8070,6 +8615,13 @@ fn verify_hashes() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -9490,6 +10042,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -9499,6 +10052,9 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -9567,11 +10123,13 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -9594,6 +10152,13 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -9680,6 +10245,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -9689,6 +10255,9 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -9757,11 +10326,13 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -9784,6 +10355,13 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -9863,6 +10441,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -9872,6 +10451,9 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -9940,11 +10522,13 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -9967,6 +10551,13 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -10044,6 +10635,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -10053,6 +10645,9 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -10121,11 +10716,13 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -10148,6 +10745,13 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -10226,6 +10830,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -10235,6 +10840,9 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -10303,11 +10911,13 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -10330,6 +10940,13 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -10409,6 +11026,7 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -10418,6 +11036,9 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -10486,11 +11107,13 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -10513,6 +11136,13 @@ fn upgrade_pip_cli_config_interaction() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -11389,6 +12019,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -11398,6 +12029,9 @@ fn build_isolation_override() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -11466,11 +12100,13 @@ fn build_isolation_override() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -11493,6 +12129,13 @@ fn build_isolation_override() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
@@ -11567,6 +12210,7 @@ fn build_isolation_override() -> anyhow::Result<()> {
         ],
         constraints: [],
         overrides: [],
+        dependency_overrides_file: [],
         excludes: [],
         build_constraints: [],
         constraints_from_workspace: [],
@@ -11576,6 +12220,9 @@ fn build_isolation_override() -> anyhow::Result<()> {
         environments: SupportedEnvironments(
             [],
         ),
+        check_url: None,
+        fail_on_license: None,
+        python_requires: None,
         refresh: None(
             Timestamp(
                 SystemTime {
@@ -11650,11 +12297,13 @@ fn build_isolation_override() -> anyhow::Result<()> {
                 {},
             ),
             output_file: None,
+            constraint_output: None,
             no_strip_extras: false,
             no_strip_markers: false,
             no_annotate: false,
             no_header: false,
             custom_compile_command: None,
+            header_comment: None,
             generate_hashes: false,
             config_setting: ConfigSettings(
                 {},
@@ -11677,6 +12326,13 @@ fn build_isolation_override() -> anyhow::Result<()> {
             emit_build_options: false,
             emit_marker_expression: false,
             emit_index_annotation: false,
+            emit_direct_only: false,
+            emit_package_urls: false,
+            emit_license: false,
+            emit_extras: false,
+            emit_tree: false,
+            group_by_extra: false,
+            strict_extras: false,
             annotation_style: Split,
             link_mode: Clone,
             compile_bytecode: false,
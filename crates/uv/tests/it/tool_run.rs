@@ -2348,6 +2348,61 @@ fn tool_run_python_at_version() {
     ");
 }
 
+#[test]
+fn tool_run_python_path() {
+    let context = uv_test::test_context_with_versions!(&["3.12", "3.11"])
+        .with_filtered_counts()
+        .with_filtered_python_sources();
+
+    // Use the Python 3.11 interpreter, even though Python 3.12 would be discovered first.
+    let python_path = context.python_versions[1].1.clone();
+
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--python-path")
+        .arg(&python_path)
+        .arg("python")
+        .arg("--version"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Python 3.11.[X]
+
+    ----- stderr -----
+    Resolved in [TIME]
+    Checked in [TIME]
+    ");
+
+    // `--python-path` takes precedence over `UV_PYTHON`.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--python-path")
+        .arg(&python_path)
+        .arg("python")
+        .arg("--version")
+        .env(EnvVars::UV_PYTHON, "3.12"), @"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Python 3.11.[X]
+
+    ----- stderr -----
+    Resolved in [TIME]
+    ");
+
+    // A missing interpreter is an error, rather than falling back to discovery.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--python-path")
+        .arg(context.temp_dir.child("missing").child("python").path())
+        .arg("python")
+        .arg("--version"), @"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No interpreter found at path `missing/python`
+    ");
+}
+
 #[test]
 fn tool_run_hint_version_not_available() {
     let context = uv_test::test_context_with_versions!(&[])